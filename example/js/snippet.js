@@ -0,0 +1,3 @@
+export function decorate(text) {
+  return `[${text}]`;
+}
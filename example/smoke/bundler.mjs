@@ -0,0 +1,8 @@
+// node --experimental-wasm-modules smoke/bundler.mjs, after
+// `wasm-pack build --target bundler --out-dir pkg-bundler -- --features target-bundler`
+import assert from "node:assert/strict";
+import { decorate, greet } from "../pkg-bundler/wasm_example.js";
+
+assert.equal(greet("bundler"), "Hello, bundler!");
+assert.equal(decorate("bundler"), "[bundler]");
+console.log("bundler: ok");
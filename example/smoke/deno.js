@@ -0,0 +1,8 @@
+// deno run --allow-read smoke/deno.js, after
+// `wasm-pack build --target deno --out-dir pkg-deno -- --features target-deno`
+import assert from "node:assert/strict";
+import { greet, inspect } from "../pkg-deno/wasm_example.js";
+
+assert.equal(greet("deno"), "Hello, deno!");
+assert.equal(inspect({ answer: 42 }), "{ answer: 42 }");
+console.log("deno: ok");
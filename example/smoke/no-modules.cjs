@@ -0,0 +1,16 @@
+// node smoke/no-modules.cjs, after
+// `wasm-pack build --target no-modules --out-dir pkg-no-modules -- --features target-no-modules`
+const assert = require("node:assert/strict");
+const fs = require("node:fs");
+const path = require("node:path");
+const vm = require("node:vm");
+
+// Evaluate the glue as a classic script, the way a <script> tag would.
+const dir = path.join(__dirname, "..", "pkg-no-modules");
+vm.runInThisContext(fs.readFileSync(path.join(dir, "wasm_example.js"), "utf8"));
+const wasm_bindgen = vm.runInThisContext("wasm_bindgen");
+wasm_bindgen.initSync({ module: fs.readFileSync(path.join(dir, "wasm_example_bg.wasm")) });
+
+assert.equal(wasm_bindgen.greet("no-modules"), "Hello, no-modules!");
+assert.equal(wasm_bindgen.has_loader_global(), true);
+console.log("no-modules: ok");
@@ -0,0 +1,121 @@
+// node smoke/nodejs.cjs, after `wasm-pack build --target nodejs --out-dir pkg-nodejs -- --features target-nodejs`
+const assert = require("node:assert/strict");
+const os = require("node:os");
+const {
+  add_i128,
+  apply_gain,
+  checksum,
+  echo_f32,
+  echo_f64,
+  echo_i64,
+  echo_u64,
+  echo_usize,
+  f64_bits,
+  greet,
+  greet_with,
+  invert,
+  normalize,
+  os_platform,
+  salutation_for,
+  tone_of,
+  ByteBuffer,
+  Listeners,
+  Salutation,
+} = require("../pkg-nodejs/wasm_example.js");
+
+assert.equal(greet("nodejs"), "Hello, nodejs!");
+assert.equal(os_platform(), os.platform());
+
+// Slices are copied in: the caller's array is untouched.
+const bytes = new Uint8Array([0, 200, 255]);
+assert.equal(checksum(bytes), 455);
+const inverted = invert(bytes);
+assert.ok(inverted instanceof Uint8Array);
+assert.deepEqual([...inverted], [255, 55, 0]);
+assert.deepEqual([...bytes], [0, 200, 255]);
+
+// `&mut` slices are copied back into the caller's array.
+const samples = new Float32Array([0.5, -1, 2]);
+apply_gain(samples, 2);
+assert.deepEqual([...samples], [1, -2, 4]);
+
+// `Vec<f64>` comes back as a fresh Float64Array.
+const normalized = normalize(new Float64Array([2, 4, 6]));
+assert.ok(normalized instanceof Float64Array);
+assert.deepEqual([...normalized], [0, 0.5, 1]);
+
+// `with_view()` aliases wasm memory; `to_array()` does not.
+const buffer = new ByteBuffer(4);
+const copy = buffer.to_array();
+buffer.with_view((view) => {
+  view[2] = 42;
+});
+assert.equal(buffer.get(2), 42);
+assert.equal(copy[2], 0);
+buffer.free();
+
+// The buffer is borrowed while the callback runs, so it can't be freed
+// under the view.
+const borrowed = new ByteBuffer(4);
+borrowed.with_view(() => {
+  assert.throws(() => borrowed.free(), /while it was borrowed/);
+});
+
+// A target without `addEventListener` throws from `listen` and leaves the
+// object usable.
+const listeners = new Listeners();
+assert.throws(() => listeners.listen({}, "tick"), TypeError);
+assert.equal(listeners.active, 0);
+const target = new EventTarget();
+listeners.listen(target, "tick");
+target.dispatchEvent(new Event("tick"));
+assert.equal(listeners.received, 1);
+listeners.free();
+
+// 64-bit integers are BigInt both ways and exact past 2**53.
+const U64_MAX = 2n ** 64n - 1n;
+const I64_MIN = -(2n ** 63n);
+assert.equal(echo_u64(U64_MAX), U64_MAX);
+assert.equal(echo_u64(2n ** 53n + 1n), 2n ** 53n + 1n);
+assert.equal(echo_i64(I64_MIN), I64_MIN);
+assert.equal(typeof echo_i64(0n), "bigint");
+assert.throws(() => echo_u64(1), TypeError);
+
+// Everything else is a number, with -0 and NaN intact.
+assert.equal(typeof echo_usize(0), "number");
+assert.equal(echo_usize(2 ** 32 - 1), 2 ** 32 - 1);
+assert.ok(Object.is(echo_f64(-0), -0));
+assert.ok(Number.isNaN(echo_f64(NaN)));
+assert.equal(f64_bits(-0), 2n ** 63n);
+assert.equal(echo_f64(Number.MAX_SAFE_INTEGER + 2), Number.MAX_SAFE_INTEGER + 2);
+assert.equal(echo_f32(0.1), Math.fround(0.1));
+assert.ok(Object.is(echo_f32(-0), -0));
+
+// i128 goes through decimal strings.
+assert.equal(add_i128("170141183460469231731687303715884105726", "1"), String(2n ** 127n - 1n));
+assert.throws(() => add_i128(String(2n ** 127n - 1n), "1"), /overflow/);
+
+// C-style enums are frozen objects of numbers. Out-of-range discriminants
+// throw, but only after the glue coerces to `u32`: `undefined`, `1.5`, "1"
+// and `2 ** 32` all pick a variant.
+assert.ok(Object.isFrozen(Salutation));
+assert.equal(Salutation.Welcome, 2);
+assert.equal(Salutation[2], "Welcome");
+assert.equal(greet_with(Salutation.Hi, "Ada"), "Hi, Ada!");
+for (const invalid of [3, -1]) {
+  assert.throws(() => greet_with(invalid, "Ada"), { message: "invalid enum value passed" });
+}
+assert.equal(greet_with(undefined, "Ada"), "Hello, Ada!");
+assert.equal(greet_with(1.5, "Ada"), "Hi, Ada!");
+assert.equal(greet_with("1", "Ada"), "Hi, Ada!");
+assert.equal(greet_with(2 ** 32, "Ada"), "Hello, Ada!");
+assert.throws(() => tone_of(9), { message: "invalid enum value passed" });
+
+// String enums are plain strings; anything else reaches Rust as `__Invalid`.
+assert.equal(salutation_for("formal"), Salutation.Welcome);
+assert.equal(tone_of(Salutation.Hi), "casual");
+for (const invalid of ["Formal", "", null, 0]) {
+  assert.throws(() => salutation_for(invalid), { message: "unknown tone" });
+}
+
+console.log("nodejs: ok");
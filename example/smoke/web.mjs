@@ -0,0 +1,11 @@
+// node smoke/web.mjs, after `wasm-pack build --target web --out-dir pkg-web -- --features target-web`
+import assert from "node:assert/strict";
+import { readFile } from "node:fs/promises";
+import init, { greet, module_url } from "../pkg-web/wasm_example.js";
+
+const bytes = await readFile(new URL("../pkg-web/wasm_example_bg.wasm", import.meta.url));
+await init({ module_or_path: bytes });
+
+assert.equal(greet("web"), "Hello, web!");
+assert.match(module_url(), /^file:.*\/snippets\//);
+console.log("web: ok");
@@ -0,0 +1,95 @@
+//! Global allocator selection and an allocation-heavy workload to compare
+//! them with. The wasm build uses std's dlmalloc unless the `talc` or
+//! `lol_alloc` feature picks another; native builds keep the system one.
+
+use std::collections::BTreeMap;
+
+use js_sys::WebAssembly;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+// Both allocators below skip locking, which is only sound without threads.
+#[cfg(all(
+    target_feature = "atomics",
+    any(feature = "talc", feature = "lol_alloc")
+))]
+compile_error!("the `talc` and `lol_alloc` features are single-threaded; build without `atomics`");
+
+#[cfg(all(target_arch = "wasm32", feature = "talc"))]
+#[global_allocator]
+// SAFETY: wasm32 without `atomics` has a single thread.
+static ALLOCATOR: talc::TalckWasm = unsafe { talc::TalckWasm::new_global() };
+
+#[cfg(all(target_arch = "wasm32", feature = "lol_alloc", not(feature = "talc")))]
+#[global_allocator]
+// SAFETY: wasm32 without `atomics` has a single thread.
+static ALLOCATOR: lol_alloc::AssumeSingleThreaded<lol_alloc::FreeListAllocator> =
+    unsafe { lol_alloc::AssumeSingleThreaded::new(lol_alloc::FreeListAllocator::new()) };
+
+/// Name of the global allocator this build was compiled with.
+#[wasm_bindgen]
+pub fn allocator() -> String {
+    if cfg!(feature = "talc") {
+        "talc"
+    } else if cfg!(feature = "lol_alloc") {
+        "lol_alloc"
+    } else {
+        "dlmalloc"
+    }
+    .to_string()
+}
+
+/// Runs `rounds` rounds of mixed-size allocations, frees and reallocations,
+/// and returns a checksum of what was built. The result depends only on
+/// `rounds`, so it must match across allocators.
+#[wasm_bindgen]
+pub fn churn(rounds: u32) -> u32 {
+    let mut seed = 0x2545_f491u32;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+
+    let mut checksum = 0u32;
+    let mut survivors: Vec<Vec<u8>> = Vec::new();
+    for round in 0..rounds {
+        let mut words: BTreeMap<String, u32> = BTreeMap::new();
+        for _ in 0..64 {
+            let len = (next() % 48) as usize + 1;
+            let word: String = (0..len)
+                .map(|i| char::from(b'a' + ((round as usize + i) % 26) as u8))
+                .collect();
+            *words.entry(word).or_insert(0) += 1;
+        }
+
+        let mut grown = Vec::new();
+        for (word, count) in &words {
+            grown.extend_from_slice(word.as_bytes());
+            checksum = checksum.wrapping_mul(31).wrapping_add(*count);
+        }
+        checksum = grown
+            .iter()
+            .fold(checksum, |sum, &byte| sum.wrapping_add(u32::from(byte)));
+
+        // Keep a few blocks of varying size alive to fragment the heap.
+        survivors.push(vec![round as u8; (next() % 4096) as usize]);
+        if survivors.len() > 16 {
+            let evicted = survivors.swap_remove((next() % 16) as usize);
+            checksum = checksum.wrapping_add(evicted.len() as u32);
+        }
+    }
+    checksum
+}
+
+/// Current size of the wasm linear memory in bytes. Memory never shrinks,
+/// so this is also its peak.
+#[wasm_bindgen]
+pub fn memory_bytes() -> u32 {
+    wasm_bindgen::memory()
+        .unchecked_into::<WebAssembly::Memory>()
+        .buffer()
+        .unchecked_into::<js_sys::ArrayBuffer>()
+        .byte_length()
+}
@@ -0,0 +1,103 @@
+//! Bulk numeric data. Slice arguments are copied into wasm memory for the
+//! call (and `&mut` slices copied back out afterwards); `Vec`/`Box<[T]>`
+//! returns are copied into a fresh typed array. `ByteBuffer::with_view` is
+//! the zero-copy alternative.
+
+use js_sys::{Function, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+/// Sum of `bytes`, wrapping at `u32::MAX`.
+#[wasm_bindgen]
+pub fn checksum(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0u32, |sum, &byte| sum.wrapping_add(u32::from(byte)))
+}
+
+/// Multiplies every sample by `gain`. JS sees the result in the
+/// `Float32Array` it passed in once the call returns.
+#[wasm_bindgen]
+pub fn apply_gain(samples: &mut [f32], gain: f32) {
+    for sample in samples.iter_mut() {
+        *sample *= gain;
+    }
+}
+
+/// Rescales `values` into `0.0..=1.0`, returning a new `Float64Array`.
+#[wasm_bindgen]
+pub fn normalize(values: &[f64]) -> Vec<f64> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    values
+        .iter()
+        .map(|&value| {
+            if range > 0.0 {
+                (value - min) / range
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Returns a new `Uint8Array` holding `255 - byte` for each input byte.
+#[wasm_bindgen]
+pub fn invert(bytes: &[u8]) -> Box<[u8]> {
+    bytes.iter().map(|&byte| 255 - byte).collect()
+}
+
+/// A byte buffer owned by wasm memory and exposed to JS without copying.
+#[wasm_bindgen]
+pub struct ByteBuffer {
+    data: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl ByteBuffer {
+    #[wasm_bindgen(constructor)]
+    pub fn new(len: usize) -> ByteBuffer {
+        ByteBuffer { data: vec![0; len] }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<u8> {
+        self.data.get(index).copied()
+    }
+
+    pub fn fill(&mut self, value: u8) {
+        self.data.fill(value);
+    }
+
+    /// Calls `f` with a `Uint8Array` aliasing the buffer's bytes in wasm
+    /// memory and returns what `f` returns. Writes through the view land in
+    /// the buffer without a copy.
+    ///
+    /// The view is only valid until `f` returns. While it runs, the buffer is
+    /// borrowed: calling back into it from `f`, `free()` included, throws
+    /// and leaks the buffer. A view kept past `f` still points at the bytes,
+    /// and after `free()` it reads and writes freed heap memory, so never let
+    /// it escape `f`. It is also detached if `f` grows wasm memory.
+    pub fn with_view(&mut self, f: &Function) -> Result<JsValue, JsValue> {
+        // SAFETY: the view aliases `self.data`, which `&mut self` keeps alive
+        // and unmoved, with no other access, for the whole call to `f`. A
+        // view `f` stores elsewhere outlives that guarantee and can reach
+        // freed memory after `free()`; the doc comment forbids it, since JS
+        // can't be stopped from holding on to an object.
+        let view = unsafe { Uint8Array::view_mut_raw(self.data.as_mut_ptr(), self.data.len()) };
+        f.call1(&JsValue::NULL, &view)
+    }
+
+    /// A `Uint8Array` holding a copy of the buffer's bytes.
+    pub fn to_array(&self) -> Uint8Array {
+        Uint8Array::from(&self.data[..])
+    }
+}
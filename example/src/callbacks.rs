@@ -0,0 +1,149 @@
+//! Calling JS functions from Rust and handing Rust closures to JS.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Anything with `addEventListener`/`removeEventListener`, e.g. a DOM node
+    /// or a bare `new EventTarget()`.
+    type Emitter;
+
+    // `catch` because `listen` takes any value, which need not have either
+    // method.
+    #[wasm_bindgen(method, structural, catch, js_name = addEventListener)]
+    fn add_event_listener(this: &Emitter, event: &str, listener: &Function) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, structural, catch, js_name = removeEventListener)]
+    fn remove_event_listener(
+        this: &Emitter,
+        event: &str,
+        listener: &Function,
+    ) -> Result<(), JsValue>;
+}
+
+/// Calls `callback(i)` for `i` in `0..times` and sums the numeric results.
+#[wasm_bindgen]
+pub fn call_repeatedly(callback: &Function, times: u32) -> Result<f64, JsValue> {
+    let mut total = 0.0;
+    for i in 0..times {
+        let result = callback.call1(&JsValue::NULL, &i.into())?;
+        total += result.as_f64().unwrap_or(0.0);
+    }
+    Ok(total)
+}
+
+/// Returns a JS function that yields `start`, `start + 1`, ... on each call.
+///
+/// Ownership of the closure moves to JS, which frees it once the function is
+/// garbage collected.
+#[wasm_bindgen]
+pub fn make_counter(start: u32) -> Function {
+    let mut next = start;
+    Closure::<dyn FnMut() -> u32>::new(move || {
+        let current = next;
+        next += 1;
+        current
+    })
+    .into_js_value()
+    .unchecked_into()
+}
+
+struct Registration {
+    id: u32,
+    target: Emitter,
+    event: String,
+    closure: Closure<dyn FnMut(JsValue)>,
+}
+
+impl Registration {
+    fn detach(&self) -> Result<(), JsValue> {
+        self.target
+            .remove_event_listener(&self.event, self.closure.as_ref().unchecked_ref())
+    }
+}
+
+/// Rust closures registered as event listeners. Each closure is kept alive
+/// here until `unlisten` (or `free()`) removes the listener and drops it.
+#[wasm_bindgen]
+pub struct Listeners {
+    next_id: u32,
+    received: Rc<Cell<u32>>,
+    registrations: Vec<Registration>,
+}
+
+#[wasm_bindgen]
+impl Listeners {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Listeners {
+        Listeners {
+            next_id: 0,
+            received: Rc::new(Cell::new(0)),
+            registrations: Vec::new(),
+        }
+    }
+
+    /// Adds a listener for `event` on `target` and returns its id. Throws,
+    /// registering nothing, if `target` has no working `addEventListener`.
+    pub fn listen(&mut self, target: JsValue, event: &str) -> Result<u32, JsValue> {
+        let received = Rc::clone(&self.received);
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |_event: JsValue| {
+            received.set(received.get() + 1);
+        });
+        let target: Emitter = target.unchecked_into();
+        target.add_event_listener(event, closure.as_ref().unchecked_ref())?;
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.registrations.push(Registration {
+            id,
+            target,
+            event: event.to_string(),
+            closure,
+        });
+        Ok(id)
+    }
+
+    /// Removes the listener with `id` and frees its closure, even if the
+    /// target's `removeEventListener` throws.
+    pub fn unlisten(&mut self, id: u32) -> Result<bool, JsValue> {
+        match self.registrations.iter().position(|r| r.id == id) {
+            Some(index) => {
+                self.registrations.swap_remove(index).detach()?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Number of events delivered to any listener so far.
+    #[wasm_bindgen(getter)]
+    pub fn received(&self) -> u32 {
+        self.received.get()
+    }
+
+    /// Number of listeners still registered.
+    #[wasm_bindgen(getter)]
+    pub fn active(&self) -> usize {
+        self.registrations.len()
+    }
+}
+
+impl Default for Listeners {
+    fn default() -> Listeners {
+        Listeners::new()
+    }
+}
+
+impl Drop for Listeners {
+    fn drop(&mut self) {
+        for registration in &self.registrations {
+            // Nothing to report a failure to; the closure is freed regardless.
+            let _ = registration.detach();
+        }
+    }
+}
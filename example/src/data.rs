@@ -0,0 +1,100 @@
+//! Nested data passed as plain JS values and converted with
+//! `serde-wasm-bindgen`. Every call walks the whole JS object graph, so the
+//! cost grows with the payload rather than staying constant like `i32`s.
+//! `test_conversion_cost` logs the time for a large order; CI prints the
+//! `--features serde` build's size next to the default build's.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    pub id: u32,
+    pub customer: Customer,
+    pub items: Vec<LineItem>,
+    pub status: Status,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Customer {
+    pub name: String,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineItem {
+    pub sku: String,
+    pub quantity: u32,
+    pub unit_price_cents: u32,
+}
+
+/// Internally tagged, so JS sees `{ kind: "shipped", tracking: "..." }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Status {
+    Pending,
+    Shipped { tracking: String },
+    Cancelled { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderSummary {
+    pub id: u32,
+    pub total_cents: u64,
+    pub units: u64,
+    pub skus: Vec<String>,
+}
+
+impl Order {
+    /// Totals the order in `u64`, or `None` if even that overflows.
+    pub fn summary(&self) -> Option<OrderSummary> {
+        let mut total_cents: u64 = 0;
+        let mut units: u64 = 0;
+        for item in &self.items {
+            let cost = u64::from(item.quantity).checked_mul(u64::from(item.unit_price_cents))?;
+            total_cents = total_cents.checked_add(cost)?;
+            units = units.checked_add(u64::from(item.quantity))?;
+        }
+        Some(OrderSummary {
+            id: self.id,
+            total_cents,
+            units,
+            skus: self.items.iter().map(|item| item.sku.clone()).collect(),
+        })
+    }
+}
+
+/// Deserializes an `Order` and serializes it straight back. `tags` comes back
+/// as a JS `Map`, which is how serde-wasm-bindgen represents `HashMap`s.
+#[wasm_bindgen]
+pub fn roundtrip_order(order: JsValue) -> Result<JsValue, JsValue> {
+    let order: Order = serde_wasm_bindgen::from_value(order)?;
+    Ok(serde_wasm_bindgen::to_value(&order)?)
+}
+
+/// Totals an `Order`, returning an `OrderSummary` object. Throws if the
+/// total overflows `u64`, or is too large to be a JS number.
+#[wasm_bindgen]
+pub fn summarize_order(order: JsValue) -> Result<JsValue, JsValue> {
+    let order: Order = serde_wasm_bindgen::from_value(order)?;
+    let summary = order
+        .summary()
+        .ok_or_else(|| JsError::new("order total overflows"))?;
+    Ok(serde_wasm_bindgen::to_value(&summary)?)
+}
+
+/// Counts whitespace-separated words, returned as a plain object rather than
+/// a `Map` by using the JSON-compatible serializer.
+#[wasm_bindgen]
+pub fn word_counts(text: &str) -> Result<JsValue, JsValue> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_default() += 1;
+    }
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    Ok(counts.serialize(&serializer)?)
+}
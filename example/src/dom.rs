@@ -0,0 +1,103 @@
+//! DOM manipulation through `web-sys`, enabled by the `web` feature. Only
+//! usable on the main thread of a page.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Element, HtmlElement};
+
+fn document() -> Result<Document, JsValue> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document in this scope"))
+}
+
+#[wasm_bindgen]
+pub fn document_title() -> Result<String, JsValue> {
+    Ok(document()?.title())
+}
+
+#[wasm_bindgen]
+pub fn set_document_title(title: &str) -> Result<(), JsValue> {
+    document()?.set_title(title);
+    Ok(())
+}
+
+/// Appends a `<ul>` with one `<li>` per item to `parent` and returns it.
+#[wasm_bindgen]
+pub fn render_list(parent: &Element, items: Vec<String>) -> Result<Element, JsValue> {
+    let document = document()?;
+    let list = document.create_element("ul")?;
+    for item in &items {
+        let entry = document.create_element("li")?;
+        entry.set_text_content(Some(item));
+        list.append_child(&entry)?;
+    }
+    parent.append_child(&list)?;
+    Ok(list)
+}
+
+/// A `<button>` that counts its clicks. Freeing it removes the listener and
+/// the element.
+#[wasm_bindgen]
+pub struct ClickCounter {
+    button: HtmlElement,
+    clicks: Rc<Cell<u32>>,
+    on_click: Closure<dyn FnMut()>,
+}
+
+#[wasm_bindgen]
+impl ClickCounter {
+    #[wasm_bindgen(constructor)]
+    pub fn new(parent: &Element) -> Result<ClickCounter, JsValue> {
+        let button: HtmlElement = document()?.create_element("button")?.dyn_into()?;
+        button.set_text_content(Some(&click_label(0)));
+
+        let clicks = Rc::new(Cell::new(0));
+        let on_click = {
+            let clicks = Rc::clone(&clicks);
+            let button = button.clone();
+            Closure::<dyn FnMut()>::new(move || {
+                clicks.set(clicks.get() + 1);
+                button.set_text_content(Some(&click_label(clicks.get())));
+            })
+        };
+        button.add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())?;
+        parent.append_child(&button)?;
+
+        Ok(ClickCounter {
+            button,
+            clicks,
+            on_click,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn element(&self) -> HtmlElement {
+        self.button.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn clicks(&self) -> u32 {
+        self.clicks.get()
+    }
+}
+
+impl Drop for ClickCounter {
+    fn drop(&mut self) {
+        // Nothing useful can be done if the element is already gone.
+        let _ = self
+            .button
+            .remove_event_listener_with_callback("click", self.on_click.as_ref().unchecked_ref());
+        self.button.remove();
+    }
+}
+
+fn click_label(clicks: u32) -> String {
+    match clicks {
+        1 => "Clicked 1 time".to_string(),
+        n => format!("Clicked {} times", n),
+    }
+}
@@ -0,0 +1,88 @@
+use js_sys::{Error, Reflect};
+use wasm_bindgen::prelude::*;
+
+/// An error with a machine-readable `code`, thrown to JS as an `Error` whose
+/// `name` is `"CodedError"` and which carries a numeric `code` property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodedError {
+    code: u32,
+    message: String,
+}
+
+impl CodedError {
+    pub fn new(code: u32, message: &str) -> CodedError {
+        CodedError {
+            code,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<CodedError> for JsValue {
+    fn from(err: CodedError) -> JsValue {
+        js_error("CodedError", &err.message, &[("code", err.code.into())]).into()
+    }
+}
+
+/// An `Error` with `name` and `message`, plus `properties` for anything a
+/// caller should match on rather than parse out of the message.
+pub(crate) fn js_error(name: &str, message: &str, properties: &[(&str, JsValue)]) -> Error {
+    let error = Error::new(message);
+    error.set_name(name);
+    for (key, value) in properties {
+        // Setting a property on a fresh `Error` object cannot fail.
+        Reflect::set(&error, &JsValue::from(*key), value).unwrap_throw();
+    }
+    error
+}
+
+/// Parses a TCP port; the `ParseIntError` message becomes a plain `Error`.
+#[wasm_bindgen]
+pub fn parse_port(text: &str) -> Result<u16, JsError> {
+    Ok(text.trim().parse::<u16>()?)
+}
+
+/// Returns `value` if it lies in `min..=max`, otherwise throws a bare string.
+#[wasm_bindgen]
+pub fn check_range(value: i32, min: i32, max: i32) -> Result<i32, JsValue> {
+    if (min..=max).contains(&value) {
+        Ok(value)
+    } else {
+        Err(JsValue::from_str(&format!(
+            "{} is outside {}..={}",
+            value, min, max
+        )))
+    }
+}
+
+/// Checks that `name` is 1-16 ASCII alphanumerics or `_`.
+///
+/// Throws a `CodedError` with code 1 when empty, 2 when too long and 3 on an
+/// invalid character.
+#[wasm_bindgen]
+pub fn validate_username(name: &str) -> Result<String, CodedError> {
+    if name.is_empty() {
+        return Err(CodedError::new(1, "username is empty"));
+    }
+    if name.len() > 16 {
+        return Err(CodedError::new(2, "username is longer than 16 characters"));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+    {
+        return Err(CodedError::new(
+            3,
+            &format!("username contains invalid character {:?}", c),
+        ));
+    }
+    Ok(name.to_string())
+}
@@ -0,0 +1,53 @@
+use wasm_bindgen::prelude::*;
+
+/// A stateful greeter exported to JavaScript as a class.
+///
+/// Exercises wasm-bindgen's class glue: a constructor, methods taking
+/// `&self`/`&mut self`, getters and setters, and `free()` on the JS side.
+#[wasm_bindgen]
+pub struct Greeter {
+    salutation: String,
+    history: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl Greeter {
+    #[wasm_bindgen(constructor)]
+    pub fn new(salutation: &str) -> Greeter {
+        Greeter {
+            salutation: salutation.to_string(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Greets `name` and records it in the history.
+    pub fn greet(&mut self, name: &str) -> String {
+        self.history.push(name.to_string());
+        crate::logic::salute(&self.salutation, name)
+    }
+
+    /// Forgets every name greeted so far.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn salutation(&self) -> String {
+        self.salutation.clone()
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_salutation(&mut self, salutation: &str) {
+        self.salutation = salutation.to_string();
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn history(&self) -> Vec<String> {
+        self.history.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> usize {
+        self.history.len()
+    }
+}
@@ -1,13 +1,82 @@
 use wasm_bindgen::prelude::*;
 
+mod allocator;
+mod buffers;
+mod callbacks;
+#[cfg(feature = "serde")]
+pub mod data;
+#[cfg(feature = "web")]
+pub mod dom;
+mod errors;
+mod greeter;
+pub mod logic;
+#[cfg(feature = "net")]
+pub mod net;
+mod numbers;
+mod panic;
+mod salutation;
+#[cfg(feature = "streams")]
+pub mod streams;
+pub mod targets;
+#[cfg(feature = "threads")]
+pub mod threads;
+mod timers;
+pub mod wasm_features;
+mod worker;
+
+pub use allocator::{allocator, churn, memory_bytes};
+pub use buffers::{apply_gain, checksum, invert, normalize, ByteBuffer};
+pub use callbacks::{call_repeatedly, make_counter, Listeners};
+pub use errors::{check_range, parse_port, validate_username, CodedError};
+pub use greeter::Greeter;
+pub use numbers::{
+    add_i128, echo_f32, echo_f64, echo_i64, echo_u64, echo_usize, f32_bits, f64_bits,
+};
+pub use panic::{panic_with, set_panic_hook};
+pub use salutation::{greet_with, salutation_for, tone_of, Salutation, Tone};
+pub use timers::{delay, fail_after, sum_slowly};
+pub use worker::{elapsed_ms, scope_name, to_base64};
+
+#[wasm_bindgen]
+pub fn greet(name: &str) -> String {
+    logic::greeting(name)
+}
+
+/// Overflow traps in dev builds and wraps in release builds; use one of the
+/// explicit variants below when the inputs aren't known to be small.
+#[wasm_bindgen]
+pub fn add(a: i32, b: i32) -> i32 {
+    logic::sum(a, b)
+}
+
+/// `a + b`, throwing instead of overflowing.
+#[wasm_bindgen]
+pub fn checked_add(a: i32, b: i32) -> Result<i32, JsError> {
+    logic::checked_sum(a, b).ok_or_else(|| JsError::new("i32 overflow"))
+}
+
+/// `a + b`, wrapping around at the bounds of `i32`.
+#[wasm_bindgen]
+pub fn wrapping_add(a: i32, b: i32) -> i32 {
+    logic::wrapping_sum(a, b)
+}
+
+/// `a + b`, clamped to `i32::MIN..=i32::MAX`.
+#[wasm_bindgen]
+pub fn saturating_add(a: i32, b: i32) -> i32 {
+    logic::saturating_sum(a, b)
+}
+
+/// Returns the first whitespace-separated word of `text`, or `undefined`.
 #[wasm_bindgen]
-pub fn greet(name: &str) -> JsValue {
-    JsValue::from(format!("Hello, {}!", name))
+pub fn first_word(text: &str) -> Option<String> {
+    logic::first_word(text).map(str::to_string)
 }
 
+/// Integer division that throws instead of trapping on a zero divisor.
 #[wasm_bindgen]
-pub fn add(a: i32, b: i32) -> JsValue {
-    JsValue::from(a + b)
+pub fn divide(a: i32, b: i32) -> Result<i32, JsError> {
+    logic::checked_divide(a, b).ok_or_else(|| JsError::new("division by zero or overflow"))
 }
 
-// These functions are just examples to demonstrate the functionality of the wasm-pack-dev-toolchain.
\ No newline at end of file
+// These functions are just examples to demonstrate the functionality of the wasm-pack-dev-toolchain.
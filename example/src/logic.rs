@@ -0,0 +1,124 @@
+//! Plain Rust behind the exports. Nothing here touches `JsValue`, so it
+//! builds and tests on the host target as part of the `rlib`.
+
+/// Formats `"{salutation}, {name}!"`.
+pub fn salute(salutation: &str, name: &str) -> String {
+    format!("{}, {}!", salutation, name)
+}
+
+/// The greeting returned by `greet`.
+pub fn greeting(name: &str) -> String {
+    salute("Hello", name)
+}
+
+/// Overflow panics with debug assertions on and wraps without them.
+pub fn sum(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+/// `None` when `a + b` overflows, in every profile.
+pub fn checked_sum(a: i32, b: i32) -> Option<i32> {
+    a.checked_add(b)
+}
+
+/// `a + b`, wrapping around at the bounds of `i32` in every profile.
+pub fn wrapping_sum(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+/// `a + b`, clamped to `i32::MIN..=i32::MAX`.
+pub fn saturating_sum(a: i32, b: i32) -> i32 {
+    a.saturating_add(b)
+}
+
+pub fn first_word(text: &str) -> Option<&str> {
+    text.split_whitespace().next()
+}
+
+/// `None` on a zero divisor or `i32::MIN / -1`.
+pub fn checked_divide(a: i32, b: i32) -> Option<i32> {
+    a.checked_div(b)
+}
+
+/// Splits bytes arriving in arbitrary chunks into `\n`-terminated lines.
+#[derive(Debug, Default)]
+pub struct LineSplitter {
+    partial: Vec<u8>,
+}
+
+impl LineSplitter {
+    /// Lines completed by `chunk`, without their `\n`.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for &byte in chunk {
+            if byte == b'\n' {
+                lines.push(String::from_utf8_lossy(&self.partial).into_owned());
+                self.partial.clear();
+            } else {
+                self.partial.push(byte);
+            }
+        }
+        lines
+    }
+
+    /// The last line if the input did not end with `\n`.
+    pub fn finish(&mut self) -> Option<String> {
+        if self.partial.is_empty() {
+            return None;
+        }
+        let line = String::from_utf8_lossy(&self.partial).into_owned();
+        self.partial.clear();
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greeting_uses_hello() {
+        assert_eq!(greeting("World"), "Hello, World!");
+        assert_eq!(salute("Hi", "Ada"), "Hi, Ada!");
+    }
+
+    #[test]
+    fn first_word_skips_whitespace() {
+        assert_eq!(first_word("\t two words"), Some("two"));
+        assert_eq!(first_word(""), None);
+    }
+
+    #[test]
+    fn checked_sum_rejects_overflow() {
+        assert_eq!(checked_sum(2, 3), Some(5));
+        assert_eq!(checked_sum(i32::MAX, 1), None);
+        assert_eq!(checked_sum(i32::MIN, -1), None);
+    }
+
+    #[test]
+    fn wrapping_and_saturating_sums() {
+        assert_eq!(wrapping_sum(i32::MAX, 1), i32::MIN);
+        assert_eq!(wrapping_sum(i32::MIN, -1), i32::MAX);
+        assert_eq!(saturating_sum(i32::MAX, 1), i32::MAX);
+        assert_eq!(saturating_sum(i32::MIN, -1), i32::MIN);
+        assert_eq!(saturating_sum(2, 3), 5);
+    }
+
+    #[test]
+    fn line_splitter_joins_lines_across_chunks() {
+        let mut splitter = LineSplitter::default();
+        assert_eq!(splitter.push(b"one\ntw"), ["one"]);
+        assert!(splitter.push(b"o").is_empty());
+        assert_eq!(splitter.push(b"\n\nna\xc3"), ["two", ""]);
+        assert!(splitter.push(b"\xafve").is_empty());
+        assert_eq!(splitter.finish().as_deref(), Some("naïve"));
+        assert_eq!(splitter.finish(), None);
+    }
+
+    #[test]
+    fn checked_divide_rejects_zero_and_overflow() {
+        assert_eq!(checked_divide(7, 2), Some(3));
+        assert_eq!(checked_divide(7, 0), None);
+        assert_eq!(checked_divide(i32::MIN, -1), None);
+    }
+}
@@ -0,0 +1,160 @@
+//! JSON over `fetch`. Requests are built with web-sys `Request`/`RequestInit`,
+//! time out through an `AbortController`, and every failure is thrown as a
+//! `NetError` whose `kind` says which stage failed.
+
+use js_sys::{Error, Function, Promise, JSON};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortController, Headers, Request, RequestInit, Response};
+
+use crate::errors::js_error;
+use crate::timers::{clear_timeout, set_timeout};
+
+#[wasm_bindgen]
+extern "C" {
+    // Bound on the global object, like `setTimeout`, so it works outside a
+    // `Window` and picks up a stubbed `globalThis.fetch`.
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch_with_request(request: &Request) -> Promise;
+}
+
+/// A failed request, thrown to JS as an `Error` whose `name` is
+/// `"NetError"`, with a `kind` property and, for `"status"`, a `status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetError {
+    /// The request could not be built or sent, e.g. a bad URL or no network.
+    Network(String),
+    /// No response within the timeout, in milliseconds.
+    Timeout(u32),
+    /// A response outside `200..=299`.
+    Status { status: u16, status_text: String },
+    /// The request or response body is not JSON.
+    Body(String),
+}
+
+impl NetError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NetError::Network(_) => "network",
+            NetError::Timeout(_) => "timeout",
+            NetError::Status { .. } => "status",
+            NetError::Body(_) => "body",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            NetError::Network(message) | NetError::Body(message) => message.clone(),
+            NetError::Timeout(ms) => format!("no response within {}ms", ms),
+            NetError::Status {
+                status,
+                status_text,
+            } => format!("HTTP {} {}", status, status_text),
+        }
+    }
+
+    /// Message of whatever JS threw, which need not be an `Error`.
+    fn describe(err: &JsValue) -> String {
+        match err.dyn_ref::<Error>() {
+            Some(error) => String::from(error.message()),
+            None => format!("{:?}", err),
+        }
+    }
+}
+
+impl From<NetError> for JsValue {
+    fn from(err: NetError) -> JsValue {
+        let mut properties = vec![("kind", JsValue::from(err.kind()))];
+        if let NetError::Status { status, .. } = err {
+            properties.push(("status", status.into()));
+        }
+        js_error("NetError", &err.message(), &properties).into()
+    }
+}
+
+/// Aborts `controller` after `ms` milliseconds unless dropped first.
+struct AbortTimer {
+    id: JsValue,
+    _abort: Closure<dyn FnMut()>,
+}
+
+impl AbortTimer {
+    fn start(controller: &AbortController, ms: u32) -> AbortTimer {
+        let controller = controller.clone();
+        let abort = Closure::<dyn FnMut()>::new(move || controller.abort());
+        let id = set_timeout(abort.as_ref().unchecked_ref::<Function>(), ms as i32);
+        AbortTimer { id, _abort: abort }
+    }
+}
+
+impl Drop for AbortTimer {
+    fn drop(&mut self) {
+        clear_timeout(&self.id);
+    }
+}
+
+/// GETs `url` and parses the response body as JSON.
+#[wasm_bindgen]
+pub async fn get_json(url: String, timeout_ms: Option<u32>) -> Result<JsValue, NetError> {
+    send(&url, "GET", None, timeout_ms).await
+}
+
+/// POSTs `body` as JSON to `url` and parses the response body as JSON.
+#[wasm_bindgen]
+pub async fn post_json(
+    url: String,
+    body: JsValue,
+    timeout_ms: Option<u32>,
+) -> Result<JsValue, NetError> {
+    let text = JSON::stringify(&body).map_err(|err| NetError::Body(NetError::describe(&err)))?;
+    send(&url, "POST", Some(text.into()), timeout_ms).await
+}
+
+async fn send(
+    url: &str,
+    method: &str,
+    body: Option<JsValue>,
+    timeout_ms: Option<u32>,
+) -> Result<JsValue, NetError> {
+    let network = |err: JsValue| NetError::Network(NetError::describe(&err));
+
+    let headers = Headers::new().map_err(network)?;
+    headers.set("Accept", "application/json").map_err(network)?;
+    let init = RequestInit::new();
+    init.set_method(method);
+    if let Some(body) = &body {
+        headers
+            .set("Content-Type", "application/json")
+            .map_err(network)?;
+        init.set_body(body);
+    }
+    init.set_headers(&headers);
+
+    let controller = AbortController::new().map_err(network)?;
+    init.set_signal(Some(&controller.signal()));
+    let request = Request::new_with_str_and_init(url, &init).map_err(network)?;
+
+    let _timer = timeout_ms.map(|ms| AbortTimer::start(&controller, ms));
+    // Once aborted, both `fetch` and reading the body reject with an
+    // `AbortError`; report that as the timeout it is.
+    let timed_out = |err: JsValue, or: fn(String) -> NetError| match timeout_ms {
+        Some(ms) if controller.signal().aborted() => NetError::Timeout(ms),
+        _ => or(NetError::describe(&err)),
+    };
+
+    let response: Response = JsFuture::from(fetch_with_request(&request))
+        .await
+        .map_err(|err| timed_out(err, NetError::Network))?
+        .unchecked_into();
+    if !response.ok() {
+        return Err(NetError::Status {
+            status: response.status(),
+            status_text: response.status_text(),
+        });
+    }
+    let json = response.json().map_err(network)?;
+    JsFuture::from(json)
+        .await
+        .map_err(|err| timed_out(err, NetError::Body))
+}
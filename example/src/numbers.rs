@@ -0,0 +1,63 @@
+//! One export per primitive number type. `i64`/`u64` cross the boundary as
+//! `BigInt`, which JS must pass in as well; `f32`, `f64` and `usize` are
+//! plain `number`s. `i128` travels as a decimal string so it stays exact in
+//! any JS runtime.
+//!
+//! `wasm2js` builds split `i64` into two `i32`s at the JS interface, so the
+//! `BigInt` exports don't work there (see `cargo xtask wasm2js`).
+
+use wasm_bindgen::prelude::*;
+
+/// Returns `x` unchanged; JS passes and receives a `BigInt`.
+#[wasm_bindgen]
+pub fn echo_i64(x: i64) -> i64 {
+    x
+}
+
+/// Returns `x` unchanged; JS passes and receives a `BigInt`.
+#[wasm_bindgen]
+pub fn echo_u64(x: u64) -> u64 {
+    x
+}
+
+/// Returns `x` unchanged. JS numbers are rounded to the nearest `f32` on
+/// the way in.
+#[wasm_bindgen]
+pub fn echo_f32(x: f32) -> f32 {
+    x
+}
+
+/// Returns `x` unchanged, including `-0` and `NaN`.
+#[wasm_bindgen]
+pub fn echo_f64(x: f64) -> f64 {
+    x
+}
+
+/// Returns `x` unchanged; a `number` in `0..=2**32 - 1` on wasm32.
+#[wasm_bindgen]
+pub fn echo_usize(x: usize) -> usize {
+    x
+}
+
+/// IEEE 754 bit pattern of `x`, so JS can tell `-0` from `0`.
+#[wasm_bindgen]
+pub fn f32_bits(x: f32) -> u32 {
+    x.to_bits()
+}
+
+/// IEEE 754 bit pattern of `x` as a `BigInt`.
+#[wasm_bindgen]
+pub fn f64_bits(x: f64) -> u64 {
+    x.to_bits()
+}
+
+/// Adds two decimal `i128` strings. Throws on anything that does not parse
+/// or on overflow.
+#[wasm_bindgen]
+pub fn add_i128(a: &str, b: &str) -> Result<String, JsError> {
+    let sum = a
+        .parse::<i128>()?
+        .checked_add(b.parse::<i128>()?)
+        .ok_or_else(|| JsError::new("i128 overflow"))?;
+    Ok(sum.to_string())
+}
@@ -0,0 +1,23 @@
+use wasm_bindgen::prelude::*;
+
+/// Installs `console_error_panic_hook` so panic messages are written to
+/// `console.error` before the module traps. Does nothing unless the
+/// `console_error_panic_hook` feature is enabled.
+#[wasm_bindgen]
+pub fn set_panic_hook() {
+    // When the `console_error_panic_hook` feature is enabled, we can call the
+    // `set_panic_hook` function at least once during initialization, and then
+    // we will get better error messages if our code ever panics.
+    //
+    // For more details see
+    // https://github.com/rustwasm/console_error_panic_hook#readme
+    #[cfg(feature = "console_error_panic_hook")]
+    console_error_panic_hook::set_once();
+}
+
+/// Panics with `message`. With `panic = "abort"` this reaches JS as a
+/// `WebAssembly.RuntimeError` thrown from an `unreachable` trap.
+#[wasm_bindgen]
+pub fn panic_with(message: &str) {
+    panic!("{}", message);
+}
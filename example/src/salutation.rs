@@ -0,0 +1,58 @@
+//! Enums across the boundary. `Salutation` is C-style and reaches JS as a
+//! frozen object of numbers; `Tone` is string-valued and reaches JS as a
+//! union of string literals.
+
+use wasm_bindgen::prelude::*;
+
+/// Opening word of a greeting.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Salutation {
+    Hello = 0,
+    Hi = 1,
+    Welcome = 2,
+}
+
+impl Salutation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Salutation::Hello => "Hello",
+            Salutation::Hi => "Hi",
+            Salutation::Welcome => "Welcome",
+        }
+    }
+}
+
+/// Register a greeting is written in.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tone {
+    Formal = "formal",
+    Casual = "casual",
+}
+
+/// Formats `"{salutation}, {name}!"`; `greet` with a choice of salutation.
+#[wasm_bindgen]
+pub fn greet_with(salutation: Salutation, name: &str) -> String {
+    crate::logic::salute(salutation.as_str(), name)
+}
+
+/// The salutation to use in `tone`. Throws on a string that is not a `Tone`,
+/// which wasm-bindgen hands over as the hidden `__Invalid` variant.
+#[wasm_bindgen]
+pub fn salutation_for(tone: Tone) -> Result<Salutation, JsError> {
+    match tone {
+        Tone::Formal => Ok(Salutation::Welcome),
+        Tone::Casual => Ok(Salutation::Hi),
+        _ => Err(JsError::new("unknown tone")),
+    }
+}
+
+/// The tone `salutation` strikes.
+#[wasm_bindgen]
+pub fn tone_of(salutation: Salutation) -> Tone {
+    match salutation {
+        Salutation::Hello | Salutation::Welcome => Tone::Formal,
+        Salutation::Hi => Tone::Casual,
+    }
+}
@@ -0,0 +1,73 @@
+//! `ReadableStream<Uint8Array>` transforms. wasm-streams turns the JS stream
+//! into a Rust `Stream` and the result back into a JS one. The output only
+//! pulls from the input when its own reader asks for more, so backpressure
+//! carries through and a large input is never buffered whole.
+
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_streams::ReadableStream;
+
+use crate::logic::LineSplitter;
+
+/// The input's chunks as bytes; a chunk that is not a `Uint8Array` is an
+/// error.
+fn chunks(input: web_sys::ReadableStream) -> impl Stream<Item = Result<Vec<u8>, JsValue>> {
+    ReadableStream::from_raw(input).into_stream().map(|chunk| {
+        match chunk?.dyn_into::<Uint8Array>() {
+            Ok(bytes) => Ok(bytes.to_vec()),
+            Err(_) => Err(JsError::new("stream chunks must be Uint8Arrays").into()),
+        }
+    })
+}
+
+/// A stream of the input's chunks with ASCII letters uppercased. Other bytes
+/// pass through untouched, so UTF-8 split across chunks stays valid.
+#[wasm_bindgen]
+pub fn uppercase(input: web_sys::ReadableStream) -> web_sys::ReadableStream {
+    let output = chunks(input).map_ok(|mut bytes| {
+        bytes.make_ascii_uppercase();
+        JsValue::from(Uint8Array::from(&bytes[..]))
+    });
+    ReadableStream::from_stream(output).into_raw()
+}
+
+/// A stream of the input's lines as strings, without their `\n`. Lines may
+/// span any number of chunks.
+#[wasm_bindgen]
+pub fn lines(input: web_sys::ReadableStream) -> web_sys::ReadableStream {
+    let mut splitter = LineSplitter::default();
+    // `None` marks the end of the input, where the last partial line is due.
+    let output = chunks(input)
+        .map(Some)
+        .chain(stream::once(async { None }))
+        .flat_map(move |chunk| {
+            let lines: Vec<Result<String, JsValue>> = match chunk {
+                Some(Ok(bytes)) => splitter.push(&bytes).into_iter().map(Ok).collect(),
+                Some(Err(err)) => vec![Err(err)],
+                None => splitter.finish().into_iter().map(Ok).collect(),
+            };
+            stream::iter(lines)
+        })
+        .map_ok(JsValue::from);
+    ReadableStream::from_stream(output).into_raw()
+}
+
+/// Number of lines in the input: one per `\n`, plus an unterminated last
+/// line.
+#[wasm_bindgen]
+pub async fn count_lines(input: web_sys::ReadableStream) -> Result<u32, JsValue> {
+    let mut chunks = chunks(input);
+    let mut count = 0;
+    let mut last = None;
+    while let Some(bytes) = chunks.next().await {
+        let bytes = bytes?;
+        count += bytes.iter().filter(|&&byte| byte == b'\n').count() as u32;
+        last = bytes.last().copied().or(last);
+    }
+    if last.is_some_and(|byte| byte != b'\n') {
+        count += 1;
+    }
+    Ok(count)
+}
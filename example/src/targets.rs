@@ -0,0 +1,85 @@
+//! Exports that only make sense for one `wasm-pack build --target`, each
+//! behind the matching `target-*` feature. The scripts in `smoke/` load the
+//! package generated for every target and call into it.
+
+#[cfg(any(
+    feature = "target-web",
+    feature = "target-bundler",
+    feature = "target-nodejs",
+    feature = "target-no-modules",
+    feature = "target-deno"
+))]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "target-web")]
+#[wasm_bindgen(inline_js = "export function module_url() { return import.meta.url; }")]
+extern "C" {
+    #[wasm_bindgen(js_name = module_url)]
+    fn snippet_module_url() -> String;
+}
+
+/// URL of the ES module the glue was loaded from. Only the ES module targets
+/// have `import.meta`.
+#[cfg(feature = "target-web")]
+#[wasm_bindgen]
+pub fn module_url() -> String {
+    snippet_module_url()
+}
+
+#[cfg(feature = "target-bundler")]
+#[wasm_bindgen(module = "/js/snippet.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = decorate)]
+    fn snippet_decorate(text: &str) -> String;
+}
+
+/// Round-trips `text` through a local JS snippet, which wasm-pack copies into
+/// `snippets/` for the bundler to resolve.
+#[cfg(feature = "target-bundler")]
+#[wasm_bindgen]
+pub fn decorate(text: &str) -> String {
+    snippet_decorate(text)
+}
+
+#[cfg(feature = "target-nodejs")]
+#[wasm_bindgen(module = "node:os")]
+extern "C" {
+    #[wasm_bindgen(js_name = platform)]
+    fn node_platform() -> String;
+}
+
+/// `os.platform()`, imported from a Node built-in rather than touching `fs`.
+#[cfg(feature = "target-nodejs")]
+#[wasm_bindgen]
+pub fn os_platform() -> String {
+    node_platform()
+}
+
+#[cfg(feature = "target-no-modules")]
+#[wasm_bindgen]
+extern "C" {
+    // The classic-script glue declares this binding at the top level.
+    #[wasm_bindgen(thread_local_v2, js_name = wasm_bindgen)]
+    static LOADER: JsValue;
+}
+
+/// Whether the `wasm_bindgen` global declared by the no-modules glue is visible.
+#[cfg(feature = "target-no-modules")]
+#[wasm_bindgen]
+pub fn has_loader_global() -> bool {
+    LOADER.with(|loader| loader.is_function() || loader.is_object())
+}
+
+#[cfg(feature = "target-deno")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = Deno, js_name = inspect)]
+    fn deno_inspect(value: &JsValue) -> String;
+}
+
+/// Formats `value` with `Deno.inspect`.
+#[cfg(feature = "target-deno")]
+#[wasm_bindgen]
+pub fn inspect(value: &JsValue) -> String {
+    deno_inspect(value)
+}
@@ -0,0 +1,33 @@
+//! Parallel reductions on a rayon pool backed by Web Workers sharing this
+//! module's memory. JS must `await initThreadPool(n)` once before calling
+//! anything here; see `.cargo/threads.toml` for how to build it.
+
+use rayon::prelude::*;
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+pub use wasm_bindgen_rayon::init_thread_pool;
+
+/// Sum of `values`, split across the pool.
+#[wasm_bindgen]
+pub fn parallel_sum(values: &[i32]) -> f64 {
+    values
+        .par_iter()
+        .map(|&value| i64::from(value))
+        .sum::<i64>() as f64
+}
+
+/// Number of primes below `limit`, checked in parallel by trial division.
+#[wasm_bindgen]
+pub fn parallel_count_primes(limit: u32) -> u32 {
+    (2..limit)
+        .into_par_iter()
+        .filter(|&n| (2..).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .count() as u32
+}
+
+/// Threads in the current pool; `1` until the pool is initialized.
+#[wasm_bindgen]
+pub fn thread_count() -> usize {
+    rayon::current_num_threads()
+}
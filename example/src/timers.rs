@@ -0,0 +1,45 @@
+use js_sys::{Function, Promise};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::errors::js_error;
+
+#[wasm_bindgen]
+extern "C" {
+    // Bound on the global object so it resolves in browsers, workers and Node alike.
+    #[wasm_bindgen(js_name = setTimeout)]
+    pub(crate) fn set_timeout(handler: &Function, timeout: i32) -> JsValue;
+
+    #[cfg(feature = "net")]
+    #[wasm_bindgen(js_name = clearTimeout)]
+    pub(crate) fn clear_timeout(id: &JsValue);
+}
+
+/// Resolves after `ms` milliseconds.
+#[wasm_bindgen]
+pub async fn delay(ms: i32) -> Result<(), JsValue> {
+    let promise = Promise::new(&mut |resolve, _reject| {
+        set_timeout(&resolve, ms);
+    });
+    JsFuture::from(promise).await?;
+    Ok(())
+}
+
+/// Adds `values` one at a time, waiting `ms` milliseconds between steps.
+#[wasm_bindgen]
+pub async fn sum_slowly(values: Vec<i32>, ms: i32) -> Result<i32, JsValue> {
+    let mut total = 0;
+    for value in values {
+        delay(ms).await?;
+        total = crate::add(total, value);
+    }
+    Ok(total)
+}
+
+/// Rejects after `ms` milliseconds with an `Error` carrying a `code` property.
+#[wasm_bindgen]
+pub async fn fail_after(ms: i32, code: u32) -> Result<(), JsValue> {
+    delay(ms).await?;
+    let message = format!("failed after {}ms", ms);
+    Err(js_error("TimeoutError", &message, &[("code", code.into())]).into())
+}
@@ -0,0 +1,126 @@
+//! Exports that only compile to the instructions of a post-MVP WebAssembly
+//! feature. Each cargo feature must be paired with the matching
+//! `RUSTFLAGS="-C target-feature=+..."`. rustc's default wasm32 target
+//! already has bulk memory, reference types and multi-value, so only `simd`
+//! needs the flag there; `cargo xtask features` builds each on the MVP
+//! baseline instead, where the guards below hold for all four, and checks
+//! `wasm-opt` accepts each with its `--enable-*` flag.
+
+#[cfg(any(
+    feature = "simd",
+    feature = "bulk-memory",
+    feature = "reference-types",
+    feature = "multivalue"
+))]
+use wasm_bindgen::prelude::*;
+
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "simd",
+    not(target_feature = "simd128")
+))]
+compile_error!("the `simd` feature needs RUSTFLAGS=\"-C target-feature=+simd128\"");
+
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "bulk-memory",
+    not(target_feature = "bulk-memory")
+))]
+compile_error!("the `bulk-memory` feature needs RUSTFLAGS=\"-C target-feature=+bulk-memory\"");
+
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "reference-types",
+    not(target_feature = "reference-types")
+))]
+compile_error!(
+    "the `reference-types` feature needs RUSTFLAGS=\"-C target-feature=+reference-types\""
+);
+
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "multivalue",
+    not(target_feature = "multivalue")
+))]
+compile_error!("the `multivalue` feature needs RUSTFLAGS=\"-C target-feature=+multivalue\"");
+
+/// Dot product of the common prefix of `a` and `b`, four lanes at a time
+/// with `f32x4` on wasm32.
+#[cfg(feature = "simd")]
+#[wasm_bindgen]
+pub fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+    let len = a.len().min(b.len());
+    let (a, b) = (&a[..len], &b[..len]);
+    let split = len - len % 4;
+
+    #[cfg(target_arch = "wasm32")]
+    let head = {
+        use core::arch::wasm32::*;
+
+        let mut sum = f32x4_splat(0.0);
+        for i in (0..split).step_by(4) {
+            // SAFETY: `i + 4 <= split <= len`, and `v128_load` has no
+            // alignment requirement.
+            let (x, y) = unsafe {
+                (
+                    v128_load(a[i..].as_ptr() as *const v128),
+                    v128_load(b[i..].as_ptr() as *const v128),
+                )
+            };
+            sum = f32x4_add(sum, f32x4_mul(x, y));
+        }
+        f32x4_extract_lane::<0>(sum)
+            + f32x4_extract_lane::<1>(sum)
+            + f32x4_extract_lane::<2>(sum)
+            + f32x4_extract_lane::<3>(sum)
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    let head: f32 = a[..split].iter().zip(&b[..split]).map(|(x, y)| x * y).sum();
+
+    head + a[split..]
+        .iter()
+        .zip(&b[split..])
+        .map(|(x, y)| x * y)
+        .sum::<f32>()
+}
+
+/// `len` copies of `value`, filled with `memory.fill`.
+#[cfg(feature = "bulk-memory")]
+#[wasm_bindgen]
+pub fn splat(len: usize, value: u8) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    bytes.resize(len, value);
+    bytes
+}
+
+/// Rotates `bytes` left by `by`, moving the bulk with `memory.copy`.
+#[cfg(feature = "bulk-memory")]
+#[wasm_bindgen]
+pub fn rotate_left(bytes: &mut [u8], by: usize) {
+    if !bytes.is_empty() {
+        bytes.rotate_left(by % bytes.len());
+    }
+}
+
+/// Returns `second` if `take_second`, otherwise `first`. Both stay
+/// `externref`s the whole way through, with no trip through the heap table.
+#[cfg(feature = "reference-types")]
+#[wasm_bindgen]
+pub fn pick(first: JsValue, second: JsValue, take_second: bool) -> JsValue {
+    if take_second {
+        second
+    } else {
+        first
+    }
+}
+
+/// `[a / b, a % b]`, returned through a multi-value `(ptr, len)` pair.
+/// An empty array when `b` is zero.
+#[cfg(feature = "multivalue")]
+#[wasm_bindgen]
+pub fn div_mod(a: i32, b: i32) -> Vec<i32> {
+    match (a.checked_div(b), a.checked_rem(b)) {
+        (Some(quotient), Some(remainder)) => vec![quotient, remainder],
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,39 @@
+//! Exports that only touch globals every worker scope provides, so they can
+//! be called from a dedicated, shared or service worker as well as a window.
+
+use js_sys::Object;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
+
+    #[wasm_bindgen(catch)]
+    fn btoa(data: &str) -> Result<String, JsValue>;
+}
+
+/// Constructor name of the current global object, e.g.
+/// `"DedicatedWorkerGlobalScope"` or `"Window"`.
+#[wasm_bindgen]
+pub fn scope_name() -> String {
+    js_sys::global()
+        .unchecked_into::<Object>()
+        .constructor()
+        .name()
+        .into()
+}
+
+/// Milliseconds since the scope started, from `performance.now()`.
+#[wasm_bindgen]
+pub fn elapsed_ms() -> f64 {
+    performance_now()
+}
+
+/// Base64-encodes `bytes` with the global `btoa`.
+#[wasm_bindgen]
+pub fn to_base64(bytes: &[u8]) -> Result<String, JsValue> {
+    let binary: String = bytes.iter().copied().map(char::from).collect();
+    btoa(&binary)
+}
@@ -0,0 +1,37 @@
+//! The allocation-heavy workload, under whichever allocator the suite was
+//! built with (`--features talc` or `--features lol_alloc`; dlmalloc if none).
+
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+async fn test_allocator_matches_features() {
+    let expected = if cfg!(feature = "talc") {
+        "talc"
+    } else if cfg!(feature = "lol_alloc") {
+        "lol_alloc"
+    } else {
+        "dlmalloc"
+    };
+    assert_eq!(wasm_example::allocator(), expected);
+}
+
+#[wasm_bindgen_test]
+async fn test_churn_checksum_is_allocator_independent() {
+    assert_eq!(wasm_example::churn(0), 0);
+    assert_eq!(wasm_example::churn(100), 1_316_082_327);
+}
+
+// Each `churn(200)` allocates about a megabyte in total, so without reuse
+// twenty more runs would grow memory by many pages. One page of slack
+// covers fragmentation left behind by whichever tests ran first.
+#[wasm_bindgen_test]
+async fn test_churn_reuses_freed_memory() {
+    const PAGE: u32 = 64 * 1024;
+
+    wasm_example::churn(200);
+    let warmed_up = wasm_example::memory_bytes();
+    for _ in 0..20 {
+        wasm_example::churn(200);
+    }
+    assert!(wasm_example::memory_bytes() <= warmed_up + PAGE);
+}
@@ -0,0 +1,162 @@
+//! Callback and closure lifetimes, checked for leaks by watching wasm memory.
+
+use js_sys::{Array, ArrayBuffer, Function, Reflect, WebAssembly};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(inline_js = r#"
+// An `EventTarget` that counts the listeners registered on it and every call
+// made to them.
+export class RecordingTarget extends EventTarget {
+    constructor() {
+        super();
+        this.wrappers = new Map();
+        this.calls = 0;
+    }
+    addEventListener(type, listener) {
+        const wrapper = (event) => {
+            this.calls++;
+            return listener(event);
+        };
+        this.wrappers.set(listener, wrapper);
+        super.addEventListener(type, wrapper);
+    }
+    removeEventListener(type, listener) {
+        super.removeEventListener(type, this.wrappers.get(listener));
+        this.wrappers.delete(listener);
+    }
+    get registered() {
+        return this.wrappers.size;
+    }
+}
+"#)]
+extern "C" {
+    type RecordingTarget;
+
+    #[wasm_bindgen(constructor)]
+    fn new() -> RecordingTarget;
+
+    #[wasm_bindgen(method, getter)]
+    fn registered(this: &RecordingTarget) -> u32;
+
+    #[wasm_bindgen(method, getter)]
+    fn calls(this: &RecordingTarget) -> u32;
+}
+
+fn memory_bytes() -> u32 {
+    wasm_bindgen::memory()
+        .unchecked_into::<WebAssembly::Memory>()
+        .buffer()
+        .unchecked_into::<ArrayBuffer>()
+        .byte_length()
+}
+
+fn global_constructor(name: &str) -> Function {
+    Reflect::get(&js_sys::global(), &name.into())
+        .unwrap()
+        .unchecked_into()
+}
+
+fn new_event_target() -> JsValue {
+    Reflect::construct(&global_constructor("EventTarget"), &Array::new()).unwrap()
+}
+
+fn dispatch(target: &JsValue, event: &str) {
+    let event =
+        Reflect::construct(&global_constructor("Event"), &Array::of1(&event.into())).unwrap();
+    let dispatch: Function = Reflect::get(target, &"dispatchEvent".into())
+        .unwrap()
+        .unchecked_into();
+    dispatch.call1(target, &event).unwrap();
+}
+
+fn listen_dispatch_unlisten(rounds: u32) {
+    let target = new_event_target();
+    let mut listeners = wasm_example::Listeners::new();
+    for _ in 0..rounds {
+        let id = listeners.listen(target.clone(), "tick").unwrap();
+        dispatch(&target, "tick");
+        assert!(listeners.unlisten(id).unwrap());
+    }
+    assert_eq!(listeners.received(), rounds);
+    assert_eq!(listeners.active(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_call_repeatedly() {
+    let double = Function::new_with_args("i", "return i * 2");
+    assert_eq!(
+        wasm_example::call_repeatedly(&double, 10_000).unwrap(),
+        99_990_000.0
+    );
+
+    let throwing = Function::new_with_args("i", "if (i === 3) throw new Error('three')");
+    assert!(wasm_example::call_repeatedly(&throwing, 10).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_make_counter() {
+    let counter = wasm_example::make_counter(5);
+    for expected in 5..1_005 {
+        assert_eq!(
+            counter.call0(&JsValue::NULL).unwrap().as_f64(),
+            Some(expected as f64)
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_listeners() {
+    let recording = RecordingTarget::new();
+    let target: &JsValue = &recording;
+    let mut listeners = wasm_example::Listeners::new();
+    let first = listeners.listen(target.clone(), "tick").unwrap();
+    listeners.listen(target.clone(), "tick").unwrap();
+
+    dispatch(target, "tick");
+    assert_eq!(listeners.received(), 2);
+
+    assert!(listeners.unlisten(first).unwrap());
+    assert!(!listeners.unlisten(first).unwrap());
+    dispatch(target, "tick");
+    assert_eq!(listeners.received(), 3);
+
+    // Dropping removes the remaining listener from the target.
+    assert_eq!(recording.registered(), 1);
+    assert_eq!(recording.calls(), 3);
+    drop(listeners);
+    assert_eq!(recording.registered(), 0);
+    dispatch(target, "tick");
+    assert_eq!(recording.calls(), 3);
+}
+
+#[wasm_bindgen_test]
+async fn test_listen_rejects_non_event_targets() {
+    let mut listeners = wasm_example::Listeners::new();
+    let error = listeners
+        .listen(js_sys::Object::new().into(), "tick")
+        .unwrap_err();
+    assert!(error.is_instance_of::<js_sys::TypeError>());
+    assert_eq!(listeners.active(), 0);
+
+    // The failed call left nothing behind, so real targets still work.
+    let target = new_event_target();
+    listeners.listen(target.clone(), "tick").unwrap();
+    dispatch(&target, "tick");
+    assert_eq!(listeners.received(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn test_listeners_do_not_leak() {
+    // Warm up so the allocator has already grown memory to its working size.
+    listen_dispatch_unlisten(1_000);
+    let before = memory_bytes();
+
+    listen_dispatch_unlisten(20_000);
+    assert_eq!(
+        memory_bytes(),
+        before,
+        "wasm memory grew across 20k listeners"
+    );
+}
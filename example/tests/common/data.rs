@@ -0,0 +1,123 @@
+//! Round trips through the `serde` feature's exports.
+
+#![cfg(feature = "serde")]
+
+use js_sys::{Map, Object, JSON};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+
+use super::get;
+
+const ORDER: &str = r#"{
+    "id": 7,
+    "customer": { "name": "Ada", "email": null },
+    "items": [
+        { "sku": "A-1", "quantity": 2, "unit_price_cents": 250 },
+        { "sku": "B-2", "quantity": 1, "unit_price_cents": 1000 }
+    ],
+    "status": { "kind": "shipped", "tracking": "1Z999" },
+    "tags": { "gift": "yes" }
+}"#;
+
+#[wasm_bindgen_test]
+async fn test_roundtrip_order() {
+    let order = wasm_example::data::roundtrip_order(JSON::parse(ORDER).unwrap()).unwrap();
+
+    assert_eq!(
+        get(&get(&order, "customer"), "name").as_string().unwrap(),
+        "Ada"
+    );
+    assert!(get(&get(&order, "customer"), "email").is_undefined());
+    assert_eq!(js_sys::Array::from(&get(&order, "items")).length(), 2);
+
+    let status = get(&order, "status");
+    assert_eq!(get(&status, "kind").as_string().unwrap(), "shipped");
+    assert_eq!(get(&status, "tracking").as_string().unwrap(), "1Z999");
+
+    let tags: Map = get(&order, "tags").dyn_into().unwrap();
+    assert_eq!(tags.get(&"gift".into()).as_string().unwrap(), "yes");
+}
+
+#[wasm_bindgen_test]
+async fn test_summarize_order() {
+    let summary = wasm_example::data::summarize_order(JSON::parse(ORDER).unwrap()).unwrap();
+    assert_eq!(get(&summary, "total_cents").as_f64(), Some(1500.0));
+    assert_eq!(get(&summary, "units").as_f64(), Some(3.0));
+    assert_eq!(
+        JSON::stringify(&get(&summary, "skus")).unwrap(),
+        r#"["A-1","B-2"]"#
+    );
+}
+
+fn bulk_order(items: &[(u32, u32)]) -> JsValue {
+    let items: Vec<String> = items
+        .iter()
+        .map(|(quantity, price)| {
+            format!(
+                r#"{{ "sku": "BULK", "quantity": {}, "unit_price_cents": {} }}"#,
+                quantity, price
+            )
+        })
+        .collect();
+    JSON::parse(&format!(
+        r#"{{ "id": 1, "customer": {{ "name": "Bulk", "email": null }}, "items": [{}], "status": {{ "kind": "pending" }} }}"#,
+        items.join(",")
+    ))
+    .unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn test_summarize_large_order() {
+    // Past `u32::MAX` cents, which a `u32` total would wrap.
+    let summary = wasm_example::data::summarize_order(bulk_order(&[(100_000, 100_000)])).unwrap();
+    assert_eq!(get(&summary, "total_cents").as_f64(), Some(1e10));
+
+    let error: js_sys::Error =
+        wasm_example::data::summarize_order(bulk_order(&[(u32::MAX, u32::MAX); 2]))
+            .unwrap_err()
+            .unchecked_into();
+    assert_eq!(String::from(error.message()), "order total overflows");
+
+    // Fits in `u64`, but not in a JS number.
+    let error = wasm_example::data::summarize_order(bulk_order(&[(u32::MAX, u32::MAX)]));
+    assert!(error.unwrap_err().is_instance_of::<js_sys::Error>());
+}
+
+#[wasm_bindgen_test]
+async fn test_summarize_order_rejects_bad_input() {
+    let order = JSON::parse(r#"{ "id": 1, "status": { "kind": "lost" } }"#).unwrap();
+    let error = wasm_example::data::summarize_order(order).unwrap_err();
+    assert!(error.is_instance_of::<js_sys::Error>());
+}
+
+#[wasm_bindgen_test]
+async fn test_word_counts() {
+    let counts = wasm_example::data::word_counts("a b a").unwrap();
+    assert!(counts.is_instance_of::<Object>() && !counts.is_instance_of::<Map>());
+    assert_eq!(get(&counts, "a").as_f64(), Some(2.0));
+    assert_eq!(get(&counts, "b").as_f64(), Some(1.0));
+}
+
+#[wasm_bindgen_test]
+async fn test_conversion_cost() {
+    let items: Vec<String> = (0..2_000)
+        .map(|i| {
+            format!(
+                r#"{{ "sku": "S-{}", "quantity": 1, "unit_price_cents": 1 }}"#,
+                i
+            )
+        })
+        .collect();
+    let order = JSON::parse(&format!(
+        r#"{{ "id": 1, "customer": {{ "name": "Bulk", "email": null }}, "items": [{}], "status": {{ "kind": "pending" }} }}"#,
+        items.join(",")
+    ))
+    .unwrap();
+
+    let start = wasm_example::elapsed_ms();
+    let summary = wasm_example::data::summarize_order(order).unwrap();
+    let elapsed = wasm_example::elapsed_ms() - start;
+
+    assert_eq!(get(&summary, "total_cents").as_f64(), Some(2000.0));
+    console_log!("summarize_order: 2000 line items in {:.2}ms", elapsed);
+}
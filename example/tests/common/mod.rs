@@ -0,0 +1,235 @@
+//! Assertions shared by every test target; each suite includes this module
+//! and picks its own runtime with `wasm_bindgen_test_configure!`.
+
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+
+mod allocator;
+mod callbacks;
+mod data;
+mod net;
+mod numbers;
+mod salutation;
+mod streams;
+mod wasm_features;
+
+/// `target[key]`, for properties the typed bindings don't cover.
+fn get(target: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(target, &key.into()).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn test_greet() {
+    assert_eq!(wasm_example::greet("World"), "Hello, World!");
+}
+
+#[wasm_bindgen_test]
+async fn test_add() {
+    assert_eq!(wasm_example::add(2, 3), 5);
+}
+
+#[wasm_bindgen_test]
+async fn test_checked_add() {
+    assert_eq!(wasm_example::checked_add(2, 3).ok(), Some(5));
+    assert!(wasm_example::checked_add(i32::MAX, 1).is_err());
+    assert!(wasm_example::checked_add(i32::MIN, -1).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_wrapping_add() {
+    assert_eq!(wasm_example::wrapping_add(2, 3), 5);
+    assert_eq!(wasm_example::wrapping_add(i32::MAX, 1), i32::MIN);
+    assert_eq!(wasm_example::wrapping_add(i32::MIN, -1), i32::MAX);
+}
+
+#[wasm_bindgen_test]
+async fn test_saturating_add() {
+    assert_eq!(wasm_example::saturating_add(2, 3), 5);
+    assert_eq!(wasm_example::saturating_add(i32::MAX, 1), i32::MAX);
+    assert_eq!(wasm_example::saturating_add(i32::MIN, -1), i32::MIN);
+}
+
+#[wasm_bindgen_test]
+async fn test_first_word() {
+    assert_eq!(
+        wasm_example::first_word("  hello world"),
+        Some("hello".to_string())
+    );
+    assert_eq!(wasm_example::first_word("   "), None);
+}
+
+#[wasm_bindgen_test]
+async fn test_divide() {
+    assert_eq!(wasm_example::divide(7, 2).ok(), Some(3));
+    assert!(wasm_example::divide(1, 0).is_err());
+    assert!(wasm_example::divide(i32::MIN, -1).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_greeter_new() {
+    let greeter = wasm_example::Greeter::new("Hi");
+    assert_eq!(greeter.salutation(), "Hi");
+    assert_eq!(greeter.count(), 0);
+    assert!(greeter.history().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn test_greeter_mutation() {
+    let mut greeter = wasm_example::Greeter::new("Hi");
+    assert_eq!(greeter.greet("Ada"), "Hi, Ada!");
+
+    greeter.set_salutation("Welcome");
+    assert_eq!(greeter.greet("Grace"), "Welcome, Grace!");
+    assert_eq!(greeter.history(), vec!["Ada", "Grace"]);
+    assert_eq!(greeter.count(), 2);
+
+    greeter.clear();
+    assert_eq!(greeter.count(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_greeter_free() {
+    let mut greeter = wasm_example::Greeter::new("Hi");
+    greeter.greet("Ada");
+    // Hand it to the JS glue, which then owns it like a `new Greeter()`.
+    let greeter = JsValue::from(greeter);
+    let call = |method: &str, args: &js_sys::Array| {
+        let method: js_sys::Function = get(&greeter, method).unchecked_into();
+        method.apply(&greeter, args)
+    };
+
+    let greeting = call("greet", &js_sys::Array::of1(&"Grace".into())).unwrap();
+    assert_eq!(greeting.as_string().unwrap(), "Hi, Grace!");
+
+    call("free", &js_sys::Array::new()).unwrap();
+    let error: js_sys::Error = call("greet", &js_sys::Array::of1(&"Ada".into()))
+        .unwrap_err()
+        .unchecked_into();
+    // `free()` zeroes the wrapper's pointer, which every method checks.
+    assert_eq!(
+        String::from(error.message()),
+        "Attempt to use a moved value"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_delay() {
+    wasm_example::delay(10).await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_sum_slowly() {
+    let total = wasm_example::sum_slowly(vec![1, 2, 3, 4], 1).await.unwrap();
+    assert_eq!(total, 10);
+}
+
+#[wasm_bindgen_test]
+async fn test_fail_after() {
+    let error = wasm_example::fail_after(1, 42).await.unwrap_err();
+    let error: js_sys::Error = error.dyn_into().unwrap();
+    assert_eq!(error.name(), "TimeoutError");
+    assert_eq!(error.message(), "failed after 1ms");
+    assert_eq!(get(&error, "code").as_f64(), Some(42.0));
+}
+
+#[wasm_bindgen_test]
+async fn test_elapsed_ms() {
+    let before = wasm_example::elapsed_ms();
+    wasm_example::delay(5).await.unwrap();
+    assert!(wasm_example::elapsed_ms() > before);
+}
+
+#[wasm_bindgen_test]
+async fn test_to_base64() {
+    assert_eq!(wasm_example::to_base64(b"wasm").unwrap(), "d2FzbQ==");
+    assert_eq!(wasm_example::to_base64(&[0xff, 0x00]).unwrap(), "/wA=");
+}
+
+#[wasm_bindgen_test]
+async fn test_parse_port() {
+    assert_eq!(wasm_example::parse_port(" 8080 ").ok(), Some(8080));
+
+    let error: js_sys::Error = JsValue::from(wasm_example::parse_port("http").unwrap_err())
+        .dyn_into()
+        .unwrap();
+    assert_eq!(error.name(), "Error");
+    assert_eq!(error.message(), "invalid digit found in string");
+}
+
+#[wasm_bindgen_test]
+async fn test_check_range() {
+    assert_eq!(wasm_example::check_range(5, 0, 10).ok(), Some(5));
+    let thrown = wasm_example::check_range(11, 0, 10).unwrap_err();
+    assert!(!thrown.is_instance_of::<js_sys::Error>());
+    assert_eq!(thrown.as_string().unwrap(), "11 is outside 0..=10");
+}
+
+#[wasm_bindgen_test]
+async fn test_validate_username() {
+    assert_eq!(wasm_example::validate_username("ada_99").unwrap(), "ada_99");
+
+    for (name, code, message) in [
+        ("", 1, "username is empty"),
+        (
+            "a_very_long_username",
+            2,
+            "username is longer than 16 characters",
+        ),
+        ("ada!", 3, "username contains invalid character '!'"),
+    ] {
+        let error: js_sys::Error =
+            JsValue::from(wasm_example::validate_username(name).unwrap_err())
+                .dyn_into()
+                .unwrap();
+        assert_eq!(error.name(), "CodedError");
+        assert_eq!(error.message(), message);
+        assert_eq!(get(&error, "code").as_f64(), Some(code as f64));
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_typed_array_functions() {
+    assert_eq!(wasm_example::checksum(&[1, 2, 255]), 258);
+    assert_eq!(&*wasm_example::invert(&[0, 200, 255]), &[255, 55, 0]);
+    assert_eq!(
+        wasm_example::normalize(&[2.0, 4.0, 6.0]),
+        vec![0.0, 0.5, 1.0]
+    );
+
+    let mut samples = [0.5f32, -1.0, 2.0];
+    wasm_example::apply_gain(&mut samples, 2.0);
+    assert_eq!(samples, [1.0, -2.0, 4.0]);
+}
+
+#[wasm_bindgen_test]
+async fn test_byte_buffer_view_aliases_memory() {
+    let mut buffer = wasm_example::ByteBuffer::new(4);
+    let write = js_sys::Function::new_with_args("view", "view[1] = 42; return view.length;");
+    assert_eq!(buffer.with_view(&write).unwrap().as_f64(), Some(4.0));
+    assert_eq!(buffer.get(1), Some(42));
+
+    buffer.fill(7);
+    let read = js_sys::Function::new_with_args("view", "return Array.from(view);");
+    let bytes = js_sys::Array::from(&buffer.with_view(&read).unwrap());
+    assert_eq!(
+        bytes
+            .iter()
+            .map(|b| b.as_f64().unwrap())
+            .collect::<Vec<_>>(),
+        vec![7.0; 4]
+    );
+
+    let throwing = js_sys::Function::new_with_args("view", "throw new Error('no');");
+    assert!(buffer.with_view(&throwing).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_byte_buffer_to_array_copies() {
+    let mut buffer = wasm_example::ByteBuffer::new(2);
+    let copy = buffer.to_array();
+    copy.set_index(0, 9);
+    assert_eq!(buffer.get(0), Some(0));
+
+    buffer.fill(3);
+    assert_eq!(copy.to_vec(), vec![9, 0]);
+}
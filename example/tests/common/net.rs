@@ -0,0 +1,182 @@
+//! `net` exports against a stubbed `globalThis.fetch`, so nothing touches the
+//! network. The stub answers from a fixed route table and records every
+//! request it sees.
+
+#![cfg(feature = "net")]
+
+use js_sys::{Array, Error, JSON};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+use super::get;
+use wasm_example::net::{self, NetError};
+
+#[wasm_bindgen(inline_js = r#"
+const ROUTES = {
+    "https://api.test/user": () => Response.json({ id: 7, name: "Ada" }),
+    "https://api.test/echo": async (request) =>
+        Response.json(
+            {
+                method: request.method,
+                contentType: request.headers.get("Content-Type"),
+                body: JSON.parse(await request.text()),
+            },
+            { status: 201 },
+        ),
+    "https://api.test/missing": () => new Response("gone", { status: 404, statusText: "Not Found" }),
+    "https://api.test/html": () => new Response("<html></html>", { status: 200 }),
+    "https://api.test/offline": () => Promise.reject(new TypeError("Failed to fetch")),
+    // Never answers; only the request's signal can end it.
+    "https://api.test/slow": (request) =>
+        new Promise((_, reject) => {
+            request.signal.addEventListener("abort", () =>
+                reject(new DOMException("The operation was aborted.", "AbortError")),
+            );
+        }),
+};
+
+// Replaces `globalThis.fetch` until the returned function is called, and
+// pushes every `Request` it receives onto `requests`.
+export function stub_fetch(requests) {
+    const original = globalThis.fetch;
+    globalThis.fetch = async (request) => {
+        requests.push(request);
+        return ROUTES[request.url](request);
+    };
+    return () => {
+        globalThis.fetch = original;
+    };
+}
+"#)]
+extern "C" {
+    fn stub_fetch(requests: &Array) -> js_sys::Function;
+}
+
+/// Runs `f` with `fetch` stubbed, passing it the list of recorded requests.
+async fn with_stub<F, Fut>(f: F)
+where
+    F: FnOnce(Array) -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let requests = Array::new();
+    let restore = stub_fetch(&requests);
+    f(requests).await;
+    restore.call0(&JsValue::NULL).unwrap();
+}
+
+fn request(requests: &Array, index: u32) -> web_sys::Request {
+    requests.get(index).unchecked_into()
+}
+
+#[wasm_bindgen_test]
+async fn test_get_json() {
+    with_stub(|requests| async move {
+        let user = net::get_json("https://api.test/user".into(), None)
+            .await
+            .unwrap();
+        assert_eq!(get(&user, "id").as_f64(), Some(7.0));
+        assert_eq!(get(&user, "name").as_string().unwrap(), "Ada");
+
+        let sent = request(&requests, 0);
+        assert_eq!(sent.method(), "GET");
+        assert_eq!(
+            sent.headers().get("Accept").unwrap().as_deref(),
+            Some("application/json")
+        );
+    })
+    .await;
+}
+
+#[wasm_bindgen_test]
+async fn test_post_json_sends_body() {
+    with_stub(|requests| async move {
+        let body = JSON::parse(r#"{"sku":"A-1","quantity":2}"#).unwrap();
+        let echo = net::post_json("https://api.test/echo".into(), body, Some(1000))
+            .await
+            .unwrap();
+
+        assert_eq!(get(&echo, "method").as_string().unwrap(), "POST");
+        assert_eq!(
+            get(&echo, "contentType").as_string().unwrap(),
+            "application/json"
+        );
+        let sent = get(&echo, "body");
+        assert_eq!(get(&sent, "sku").as_string().unwrap(), "A-1");
+        assert_eq!(get(&sent, "quantity").as_f64(), Some(2.0));
+        assert_eq!(requests.length(), 1);
+    })
+    .await;
+}
+
+#[wasm_bindgen_test]
+async fn test_error_kinds() {
+    with_stub(|_| async move {
+        let error =
+            |url: &'static str| async move { net::get_json(url.into(), None).await.unwrap_err() };
+        assert_eq!(
+            error("https://api.test/missing").await,
+            NetError::Status {
+                status: 404,
+                status_text: "Not Found".into()
+            }
+        );
+        assert_eq!(error("https://api.test/html").await.kind(), "body");
+        assert_eq!(
+            error("https://api.test/offline").await,
+            NetError::Network("Failed to fetch".into())
+        );
+        assert_eq!(error("not a url").await.kind(), "network");
+
+        let unserializable = js_sys::BigInt::from(1).into();
+        let err = net::post_json("https://api.test/echo".into(), unserializable, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), "body");
+    })
+    .await;
+}
+
+#[wasm_bindgen_test]
+async fn test_timeout_aborts_request() {
+    with_stub(|requests| async move {
+        let err = net::get_json("https://api.test/slow".into(), Some(20))
+            .await
+            .unwrap_err();
+        assert_eq!(err, NetError::Timeout(20));
+        assert!(request(&requests, 0).signal().aborted());
+    })
+    .await;
+}
+
+#[wasm_bindgen_test]
+async fn test_timeout_is_cleared_after_response() {
+    with_stub(|requests| async move {
+        net::get_json("https://api.test/user".into(), Some(20))
+            .await
+            .unwrap();
+        wasm_example::delay(50).await.unwrap();
+        assert!(!request(&requests, 0).signal().aborted());
+    })
+    .await;
+}
+
+#[wasm_bindgen_test]
+async fn test_net_error_reaches_js_as_error() {
+    let error: Error = JsValue::from(NetError::Status {
+        status: 503,
+        status_text: "Service Unavailable".into(),
+    })
+    .unchecked_into();
+    assert_eq!(String::from(error.name()), "NetError");
+    assert_eq!(
+        String::from(error.message()),
+        "HTTP 503 Service Unavailable"
+    );
+    assert_eq!(get(&error, "kind").as_string().unwrap(), "status");
+    assert_eq!(get(&error, "status").as_f64(), Some(503.0));
+
+    let error: Error = JsValue::from(NetError::Timeout(20)).unchecked_into();
+    assert_eq!(get(&error, "kind").as_string().unwrap(), "timeout");
+    assert!(get(&error, "status").is_undefined());
+}
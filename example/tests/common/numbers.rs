@@ -0,0 +1,59 @@
+//! Number exports at their edge values. What JS sees at the boundary
+//! (`BigInt` vs `number`) is checked by `smoke/nodejs.cjs`.
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+async fn test_echo_64_bit_integers() {
+    for x in [i64::MIN, -1, 0, i64::MAX] {
+        assert_eq!(wasm_example::echo_i64(x), x);
+    }
+    for x in [0, 1 << 53, (1 << 53) + 1, u64::MAX] {
+        assert_eq!(wasm_example::echo_u64(x), x);
+    }
+    assert!(JsValue::from(wasm_example::echo_u64(u64::MAX)).is_bigint());
+    assert_eq!(
+        JsValue::from(wasm_example::echo_i64(i64::MIN)),
+        JsValue::bigint_from_str("-9223372036854775808")
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_echo_floats_keep_sign_and_nan() {
+    assert_eq!(
+        wasm_example::f64_bits(wasm_example::echo_f64(-0.0)),
+        1 << 63
+    );
+    assert_eq!(
+        wasm_example::f32_bits(wasm_example::echo_f32(-0.0)),
+        1 << 31
+    );
+    assert!(wasm_example::echo_f64(f64::NAN).is_nan());
+    assert!(wasm_example::echo_f32(f32::NAN).is_nan());
+    assert_eq!(wasm_example::echo_f64(f64::MIN_POSITIVE), f64::MIN_POSITIVE);
+    assert_eq!(wasm_example::echo_f32(f32::MAX), f32::MAX);
+}
+
+#[wasm_bindgen_test]
+async fn test_echo_usize() {
+    assert_eq!(wasm_example::echo_usize(usize::MAX), usize::MAX);
+    assert_eq!(
+        JsValue::from(wasm_example::echo_usize(usize::MAX)).as_f64(),
+        Some(usize::MAX as f64)
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_add_i128() {
+    assert_eq!(
+        wasm_example::add_i128("170141183460469231731687303715884105726", "1").unwrap(),
+        i128::MAX.to_string()
+    );
+    assert_eq!(
+        wasm_example::add_i128("-18446744073709551616", "18446744073709551615").unwrap(),
+        "-1"
+    );
+    assert!(wasm_example::add_i128(&i128::MAX.to_string(), "1").is_err());
+    assert!(wasm_example::add_i128("1.5", "1").is_err());
+}
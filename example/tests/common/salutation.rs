@@ -0,0 +1,45 @@
+//! C-style and string-valued enum exports. Invalid values from JS are
+//! checked by `smoke/nodejs.cjs`.
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_example::{Salutation, Tone};
+
+#[wasm_bindgen_test]
+async fn test_greet_with() {
+    assert_eq!(
+        wasm_example::greet_with(Salutation::Hello, "Ada"),
+        "Hello, Ada!"
+    );
+    assert_eq!(
+        wasm_example::greet_with(Salutation::Welcome, "Ada"),
+        "Welcome, Ada!"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_salutation_for() {
+    assert_eq!(
+        wasm_example::salutation_for(Tone::Formal).ok(),
+        Some(Salutation::Welcome)
+    );
+    assert_eq!(
+        wasm_example::salutation_for(Tone::Casual).ok(),
+        Some(Salutation::Hi)
+    );
+    assert_eq!(wasm_example::tone_of(Salutation::Hi), Tone::Casual);
+}
+
+#[wasm_bindgen_test]
+async fn test_tone_from_js_value() {
+    assert_eq!(
+        Tone::from_js_value(&JsValue::from_str("formal")),
+        Some(Tone::Formal)
+    );
+    assert_eq!(Tone::from_js_value(&JsValue::from_str("Formal")), None);
+    assert_eq!(Tone::from_js_value(&JsValue::from(1)), None);
+    assert_eq!(
+        JsValue::from(Tone::Casual).as_string().as_deref(),
+        Some("casual")
+    );
+}
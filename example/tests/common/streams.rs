@@ -0,0 +1,129 @@
+//! `ReadableStream` transforms fed several chunks at a time.
+
+#![cfg(feature = "streams")]
+
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use wasm_example::streams;
+use web_sys::ReadableStream;
+
+#[wasm_bindgen(inline_js = r#"
+// Enqueues one chunk per pull and logs each pull in `pulls`, so a test can
+// see how far ahead of its reader the transform has read.
+export function source(chunks, pulls) {
+    let next = 0;
+    return new ReadableStream(
+        {
+            pull(controller) {
+                pulls.push(next);
+                if (next < chunks.length) {
+                    controller.enqueue(chunks[next++]);
+                } else {
+                    controller.close();
+                }
+            },
+        },
+        { highWaterMark: 0 },
+    );
+}
+
+export async function read_all(stream) {
+    const chunks = [];
+    for (const reader = stream.getReader(); ; ) {
+        const { done, value } = await reader.read();
+        if (done) return chunks;
+        chunks.push(value);
+    }
+}
+
+export async function read_one(stream) {
+    return (await stream.getReader().read()).value;
+}
+"#)]
+extern "C" {
+    fn source(chunks: &Array, pulls: &Array) -> ReadableStream;
+    fn read_all(stream: &ReadableStream) -> js_sys::Promise;
+    fn read_one(stream: &ReadableStream) -> js_sys::Promise;
+}
+
+fn byte_chunks(chunks: &[&[u8]]) -> Array {
+    chunks
+        .iter()
+        .map(|&chunk| JsValue::from(Uint8Array::from(chunk)))
+        .collect()
+}
+
+async fn collect(stream: &ReadableStream) -> Array {
+    JsFuture::from(read_all(stream)).await.unwrap().into()
+}
+
+#[wasm_bindgen_test]
+async fn test_uppercase_keeps_chunks_and_split_utf8() {
+    // "naïve" with the two bytes of "ï" in different chunks.
+    let input = source(
+        &byte_chunks(&[b"hello, ", b"na\xc3", b"\xafve ", b"world"]),
+        &Array::new(),
+    );
+    let output = collect(&streams::uppercase(input)).await;
+
+    assert_eq!(output.length(), 4);
+    let bytes: Vec<u8> = output
+        .iter()
+        .flat_map(|chunk| Uint8Array::from(chunk).to_vec())
+        .collect();
+    assert_eq!(String::from_utf8(bytes).unwrap(), "HELLO, NAïVE WORLD");
+}
+
+#[wasm_bindgen_test]
+async fn test_lines_span_chunks() {
+    let input = source(
+        &byte_chunks(&[b"first li", b"ne\nsecond\n", b"\nthi", b"rd"]),
+        &Array::new(),
+    );
+    let output: Vec<String> = collect(&streams::lines(input))
+        .await
+        .iter()
+        .map(|line| line.as_string().unwrap())
+        .collect();
+
+    assert_eq!(output, ["first line", "second", "", "third"]);
+}
+
+#[wasm_bindgen_test]
+async fn test_count_lines() {
+    let count =
+        |chunks: &[&[u8]]| streams::count_lines(source(&byte_chunks(chunks), &Array::new()));
+    assert_eq!(count(&[]).await.unwrap(), 0);
+    assert_eq!(count(&[b"a\nb", b"\n", b"c"]).await.unwrap(), 3);
+    assert_eq!(count(&[b"a\n", b"", b"b\n"]).await.unwrap(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn test_non_byte_chunks_error() {
+    let chunks: Array = [JsValue::from("not bytes")].iter().collect();
+    let result = JsFuture::from(read_all(&streams::uppercase(source(
+        &chunks,
+        &Array::new(),
+    ))))
+    .await;
+    let error: js_sys::Error = result.unwrap_err().into();
+    assert_eq!(
+        String::from(error.message()),
+        "stream chunks must be Uint8Arrays"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_output_pulls_input_on_demand() {
+    let chunks: Vec<&[u8]> = vec![b"x\n"; 100];
+    let pulls = Array::new();
+    let output = streams::uppercase(source(&byte_chunks(&chunks), &pulls));
+
+    let first = JsFuture::from(read_one(&output)).await.unwrap();
+    assert_eq!(Uint8Array::from(first).to_vec(), b"X\n");
+    // The output may buffer a chunk ahead of its reader, but must not drain
+    // the whole input.
+    assert!(pulls.length() <= 3, "pulled {} times", pulls.length());
+}
@@ -0,0 +1,58 @@
+//! Exports behind the post-MVP feature fixtures, e.g.
+//! `RUSTFLAGS="-C target-feature=+simd128" wasm-pack test --node -- --features simd`.
+
+#[cfg(feature = "reference-types")]
+use wasm_bindgen::JsValue;
+#[cfg(any(
+    feature = "simd",
+    feature = "bulk-memory",
+    feature = "reference-types",
+    feature = "multivalue"
+))]
+use wasm_bindgen_test::*;
+
+#[cfg(feature = "simd")]
+#[wasm_bindgen_test]
+async fn test_dot_product() {
+    use wasm_example::wasm_features::dot_product;
+
+    let a: Vec<f32> = (1..=10).map(|x| x as f32).collect();
+    assert_eq!(dot_product(&a, &a), 385.0);
+    assert_eq!(dot_product(&a[..3], &a), 14.0);
+    assert_eq!(dot_product(&[], &[]), 0.0);
+}
+
+#[cfg(feature = "bulk-memory")]
+#[wasm_bindgen_test]
+async fn test_bulk_memory() {
+    use wasm_example::wasm_features::{rotate_left, splat};
+
+    assert_eq!(splat(1000, 7).iter().filter(|&&b| b == 7).count(), 1000);
+    let mut bytes: Vec<u8> = (0..6).collect();
+    rotate_left(&mut bytes, 8);
+    assert_eq!(bytes, [2, 3, 4, 5, 0, 1]);
+}
+
+#[cfg(feature = "reference-types")]
+#[wasm_bindgen_test]
+async fn test_pick() {
+    use wasm_example::wasm_features::pick;
+
+    let first = js_sys::Object::new();
+    let second = JsValue::from("second");
+    assert!(pick(first.clone().into(), second.clone(), false) == JsValue::from(first));
+    assert_eq!(
+        pick(JsValue::NULL, second, true).as_string().unwrap(),
+        "second"
+    );
+}
+
+#[cfg(feature = "multivalue")]
+#[wasm_bindgen_test]
+async fn test_div_mod() {
+    use wasm_example::wasm_features::div_mod;
+
+    assert_eq!(div_mod(17, 5), vec![3, 2]);
+    assert_eq!(div_mod(-17, 5), vec![-3, -2]);
+    assert!(div_mod(1, 0).is_empty());
+}
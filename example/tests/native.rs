@@ -0,0 +1,101 @@
+//! Host-target tests for the `rlib`, run with
+//! `cargo test --target x86_64-unknown-linux-gnu`.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use wasm_example::{logic, Greeter};
+
+#[test]
+fn test_greet() {
+    assert_eq!(wasm_example::greet("World"), "Hello, World!");
+}
+
+#[test]
+fn test_add() {
+    assert_eq!(wasm_example::add(2, 3), 5);
+    assert_eq!(logic::sum(-4, 4), 0);
+}
+
+#[test]
+fn test_first_word() {
+    assert_eq!(
+        wasm_example::first_word("hello world").as_deref(),
+        Some("hello")
+    );
+}
+
+#[test]
+fn test_greeter() {
+    let mut greeter = Greeter::new("Hi");
+    assert_eq!(greeter.greet("Ada"), "Hi, Ada!");
+    greeter.set_salutation("Welcome");
+    assert_eq!(greeter.greet("Grace"), "Welcome, Grace!");
+    assert_eq!(greeter.history(), vec!["Ada", "Grace"]);
+}
+
+#[test]
+fn test_validate_username() {
+    assert_eq!(wasm_example::validate_username("ada").unwrap(), "ada");
+    let err = wasm_example::validate_username("ada lovelace").unwrap_err();
+    assert_eq!(err.code(), 3);
+    assert_eq!(err.message(), "username contains invalid character ' '");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_order_summary() {
+    use wasm_example::data::{Customer, LineItem, Order, Status};
+
+    let order = Order {
+        id: 1,
+        customer: Customer {
+            name: "Ada".to_string(),
+            email: None,
+        },
+        items: vec![
+            LineItem {
+                sku: "A-1".to_string(),
+                quantity: 2,
+                unit_price_cents: 250,
+            },
+            LineItem {
+                sku: "B-2".to_string(),
+                quantity: 1,
+                unit_price_cents: 1000,
+            },
+        ],
+        status: Status::Pending,
+        tags: Default::default(),
+    };
+    let summary = order.summary().unwrap();
+    assert_eq!(summary.total_cents, 1500);
+    assert_eq!(summary.units, 3);
+    assert_eq!(summary.skus, vec!["A-1", "B-2"]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_order_summary_overflow() {
+    use wasm_example::data::{Customer, LineItem, Order, Status};
+
+    let item = |quantity, unit_price_cents| LineItem {
+        sku: "BULK".to_string(),
+        quantity,
+        unit_price_cents,
+    };
+    let mut order = Order {
+        id: 1,
+        customer: Customer {
+            name: "Bulk".to_string(),
+            email: None,
+        },
+        // Past `u32::MAX` cents, which a `u32` total would wrap.
+        items: vec![item(100_000, 100_000)],
+        status: Status::Pending,
+        tags: Default::default(),
+    };
+    assert_eq!(order.summary().unwrap().total_cents, 10_000_000_000);
+
+    order.items = vec![item(u32::MAX, u32::MAX), item(u32::MAX, u32::MAX)];
+    assert_eq!(order.summary(), None);
+}
@@ -0,0 +1,7 @@
+//! Test suite for Node.js, run with `wasm-pack test --node`.
+
+#![cfg(target_arch = "wasm32")]
+
+extern crate wasm_bindgen_test;
+
+mod common;
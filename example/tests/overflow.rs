@@ -0,0 +1,34 @@
+//! `add` on overflow, which depends on the build profile. Its own binary
+//! because the dev build traps. Run under Node in both profiles:
+//! `wasm-pack test --node [--release] -- --test overflow`.
+
+#![cfg(target_arch = "wasm32")]
+
+extern crate wasm_bindgen_test;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(inline_js = r#"
+export function call_and_catch(f) {
+    try {
+        return f();
+    } catch (e) {
+        return e;
+    }
+}
+"#)]
+extern "C" {
+    fn call_and_catch(f: &JsValue) -> JsValue;
+}
+
+#[wasm_bindgen_test]
+fn test_add_overflow_traps_in_dev_and_wraps_in_release() {
+    let overflowing = Closure::<dyn Fn() -> i32>::new(|| wasm_example::add(i32::MAX, 1));
+    let result = call_and_catch(overflowing.as_ref());
+
+    if cfg!(debug_assertions) {
+        assert!(result.is_instance_of::<js_sys::WebAssembly::RuntimeError>());
+    } else {
+        assert_eq!(result.as_f64(), Some(f64::from(i32::MIN)));
+    }
+}
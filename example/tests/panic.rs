@@ -0,0 +1,55 @@
+//! Panic-to-exception behaviour, kept in its own binary because a trap leaves
+//! the instance in an unspecified state. Run under Node with
+//! `wasm-pack test --node -- --test panic --features console_error_panic_hook`
+//! in both the dev and `--release` profiles. `wasm-pack test` never runs
+//! `wasm-opt`; the `panic_with` vector in `xtask/js/vectors.cjs` covers the
+//! optimized builds through `cargo xtask opt-diff`.
+
+#![cfg(target_arch = "wasm32")]
+
+extern crate wasm_bindgen_test;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(inline_js = r#"
+export function call_and_catch(f, logged) {
+    const original = console.error;
+    console.error = (...args) => logged.push(args.join(" "));
+    try {
+        f();
+        return undefined;
+    } catch (e) {
+        return e;
+    } finally {
+        console.error = original;
+    }
+}
+"#)]
+extern "C" {
+    fn call_and_catch(f: &JsValue, logged: &js_sys::Array) -> JsValue;
+}
+
+// A single test on purpose: the trap skips the unwinding that would reset
+// std's panic count, so after the first panic `std::thread::panicking()`
+// stays true and installing a hook would itself panic.
+#[wasm_bindgen_test]
+fn test_panic_becomes_runtime_error() {
+    wasm_example::set_panic_hook();
+
+    let logged = js_sys::Array::new();
+    let panicking = Closure::<dyn Fn()>::new(|| wasm_example::panic_with("boom")).into_js_value();
+    let error = call_and_catch(&panicking, &logged);
+
+    assert!(error.is_instance_of::<js_sys::WebAssembly::RuntimeError>());
+    let error: js_sys::Error = error.unchecked_into();
+    assert!(String::from(error.message()).contains("unreachable"));
+
+    if cfg!(feature = "console_error_panic_hook") {
+        let logged: Vec<String> = logged.iter().filter_map(|line| line.as_string()).collect();
+        assert!(
+            logged.iter().any(|line| line.contains("boom")),
+            "{:?}",
+            logged
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! Test suite for a service worker, run with `wasm-pack test --headless --chrome`.
+
+#![cfg(target_arch = "wasm32")]
+
+extern crate wasm_bindgen_test;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_service_worker);
+
+mod common;
+
+#[wasm_bindgen_test]
+async fn test_scope_name() {
+    assert_eq!(wasm_example::scope_name(), "ServiceWorkerGlobalScope");
+}
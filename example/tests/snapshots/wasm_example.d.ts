@@ -0,0 +1,302 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Register a greeting is written in.
+ */
+
+type Tone = "formal" | "casual";
+
+/**
+ * A byte buffer owned by wasm memory and exposed to JS without copying.
+ */
+export class ByteBuffer {
+    free(): void;
+    [Symbol.dispose](): void;
+    fill(value: number): void;
+    get(index: number): number | undefined;
+    is_empty(): boolean;
+    constructor(len: number);
+    /**
+     * A `Uint8Array` holding a copy of the buffer's bytes.
+     */
+    to_array(): Uint8Array;
+    /**
+     * Calls `f` with a `Uint8Array` aliasing the buffer's bytes in wasm
+     * memory and returns what `f` returns. Writes through the view land in
+     * the buffer without a copy.
+     *
+     * The view is only valid until `f` returns. While it runs, the buffer is
+     * borrowed: calling back into it from `f`, `free()` included, throws
+     * and leaks the buffer. A view kept past `f` still points at the bytes,
+     * and after `free()` it reads and writes freed heap memory, so never let
+     * it escape `f`. It is also detached if `f` grows wasm memory.
+     */
+    with_view(f: Function): any;
+    readonly len: number;
+}
+
+/**
+ * A stateful greeter exported to JavaScript as a class.
+ *
+ * Exercises wasm-bindgen's class glue: a constructor, methods taking
+ * `&self`/`&mut self`, getters and setters, and `free()` on the JS side.
+ */
+export class Greeter {
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Forgets every name greeted so far.
+     */
+    clear(): void;
+    /**
+     * Greets `name` and records it in the history.
+     */
+    greet(name: string): string;
+    constructor(salutation: string);
+    readonly count: number;
+    readonly history: string[];
+    salutation: string;
+}
+
+/**
+ * Rust closures registered as event listeners. Each closure is kept alive
+ * here until `unlisten` (or `free()`) removes the listener and drops it.
+ */
+export class Listeners {
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Adds a listener for `event` on `target` and returns its id. Throws,
+     * registering nothing, if `target` has no working `addEventListener`.
+     */
+    listen(target: any, event: string): number;
+    constructor();
+    /**
+     * Removes the listener with `id` and frees its closure, even if the
+     * target's `removeEventListener` throws.
+     */
+    unlisten(id: number): boolean;
+    /**
+     * Number of listeners still registered.
+     */
+    readonly active: number;
+    /**
+     * Number of events delivered to any listener so far.
+     */
+    readonly received: number;
+}
+
+/**
+ * Opening word of a greeting.
+ */
+export enum Salutation {
+    Hello = 0,
+    Hi = 1,
+    Welcome = 2,
+}
+
+/**
+ * Overflow traps in dev builds and wraps in release builds; use one of the
+ * explicit variants below when the inputs aren't known to be small.
+ */
+export function add(a: number, b: number): number;
+
+/**
+ * Adds two decimal `i128` strings. Throws on anything that does not parse
+ * or on overflow.
+ */
+export function add_i128(a: string, b: string): string;
+
+/**
+ * Name of the global allocator this build was compiled with.
+ */
+export function allocator(): string;
+
+/**
+ * Multiplies every sample by `gain`. JS sees the result in the
+ * `Float32Array` it passed in once the call returns.
+ */
+export function apply_gain(samples: Float32Array, gain: number): void;
+
+/**
+ * Calls `callback(i)` for `i` in `0..times` and sums the numeric results.
+ */
+export function call_repeatedly(callback: Function, times: number): number;
+
+/**
+ * Returns `value` if it lies in `min..=max`, otherwise throws a bare string.
+ */
+export function check_range(value: number, min: number, max: number): number;
+
+/**
+ * `a + b`, throwing instead of overflowing.
+ */
+export function checked_add(a: number, b: number): number;
+
+/**
+ * Sum of `bytes`, wrapping at `u32::MAX`.
+ */
+export function checksum(bytes: Uint8Array): number;
+
+/**
+ * Runs `rounds` rounds of mixed-size allocations, frees and reallocations,
+ * and returns a checksum of what was built. The result depends only on
+ * `rounds`, so it must match across allocators.
+ */
+export function churn(rounds: number): number;
+
+/**
+ * Resolves after `ms` milliseconds.
+ */
+export function delay(ms: number): Promise<void>;
+
+/**
+ * Integer division that throws instead of trapping on a zero divisor.
+ */
+export function divide(a: number, b: number): number;
+
+/**
+ * Returns `x` unchanged. JS numbers are rounded to the nearest `f32` on
+ * the way in.
+ */
+export function echo_f32(x: number): number;
+
+/**
+ * Returns `x` unchanged, including `-0` and `NaN`.
+ */
+export function echo_f64(x: number): number;
+
+/**
+ * Returns `x` unchanged; JS passes and receives a `BigInt`.
+ */
+export function echo_i64(x: bigint): bigint;
+
+/**
+ * Returns `x` unchanged; JS passes and receives a `BigInt`.
+ */
+export function echo_u64(x: bigint): bigint;
+
+/**
+ * Returns `x` unchanged; a `number` in `0..=2**32 - 1` on wasm32.
+ */
+export function echo_usize(x: number): number;
+
+/**
+ * Milliseconds since the scope started, from `performance.now()`.
+ */
+export function elapsed_ms(): number;
+
+/**
+ * IEEE 754 bit pattern of `x`, so JS can tell `-0` from `0`.
+ */
+export function f32_bits(x: number): number;
+
+/**
+ * IEEE 754 bit pattern of `x` as a `BigInt`.
+ */
+export function f64_bits(x: number): bigint;
+
+/**
+ * Rejects after `ms` milliseconds with an `Error` carrying a `code` property.
+ */
+export function fail_after(ms: number, code: number): Promise<void>;
+
+/**
+ * Returns the first whitespace-separated word of `text`, or `undefined`.
+ */
+export function first_word(text: string): string | undefined;
+
+export function greet(name: string): string;
+
+/**
+ * Formats `"{salutation}, {name}!"`; `greet` with a choice of salutation.
+ */
+export function greet_with(salutation: Salutation, name: string): string;
+
+/**
+ * Returns a new `Uint8Array` holding `255 - byte` for each input byte.
+ */
+export function invert(bytes: Uint8Array): Uint8Array;
+
+/**
+ * Returns a JS function that yields `start`, `start + 1`, ... on each call.
+ *
+ * Ownership of the closure moves to JS, which frees it once the function is
+ * garbage collected.
+ */
+export function make_counter(start: number): Function;
+
+/**
+ * Current size of the wasm linear memory in bytes. Memory never shrinks,
+ * so this is also its peak.
+ */
+export function memory_bytes(): number;
+
+/**
+ * Rescales `values` into `0.0..=1.0`, returning a new `Float64Array`.
+ */
+export function normalize(values: Float64Array): Float64Array;
+
+/**
+ * Panics with `message`. With `panic = "abort"` this reaches JS as a
+ * `WebAssembly.RuntimeError` thrown from an `unreachable` trap.
+ */
+export function panic_with(message: string): void;
+
+/**
+ * Parses a TCP port; the `ParseIntError` message becomes a plain `Error`.
+ */
+export function parse_port(text: string): number;
+
+/**
+ * The salutation to use in `tone`. Throws on a string that is not a `Tone`,
+ * which wasm-bindgen hands over as the hidden `__Invalid` variant.
+ */
+export function salutation_for(tone: Tone): Salutation;
+
+/**
+ * `a + b`, clamped to `i32::MIN..=i32::MAX`.
+ */
+export function saturating_add(a: number, b: number): number;
+
+/**
+ * Constructor name of the current global object, e.g.
+ * `"DedicatedWorkerGlobalScope"` or `"Window"`.
+ */
+export function scope_name(): string;
+
+/**
+ * Installs `console_error_panic_hook` so panic messages are written to
+ * `console.error` before the module traps. Does nothing unless the
+ * `console_error_panic_hook` feature is enabled.
+ */
+export function set_panic_hook(): void;
+
+/**
+ * Adds `values` one at a time, waiting `ms` milliseconds between steps.
+ */
+export function sum_slowly(values: Int32Array, ms: number): Promise<number>;
+
+/**
+ * Base64-encodes `bytes` with the global `btoa`.
+ */
+export function to_base64(bytes: Uint8Array): string;
+
+/**
+ * The tone `salutation` strikes.
+ */
+export function tone_of(salutation: Salutation): Tone;
+
+/**
+ * Checks that `name` is 1-16 ASCII alphanumerics or `_`.
+ *
+ * Throws a `CodedError` with code 1 when empty, 2 when too long and 3 on an
+ * invalid character.
+ */
+export function validate_username(name: string): string;
+
+/**
+ * `a + b`, wrapping around at the bounds of `i32`.
+ */
+export function wrapping_add(a: number, b: number): number;
+
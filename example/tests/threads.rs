@@ -0,0 +1,30 @@
+//! Test suite for the `threads` feature in a cross-origin isolated page:
+//!
+//! RUSTUP_TOOLCHAIN=nightly wasm-pack test --headless --chrome -- \
+//!   --test threads --features threads --config .cargo/threads.toml
+
+#![cfg(all(target_arch = "wasm32", feature = "threads"))]
+
+extern crate wasm_bindgen_test;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use wasm_example::threads;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// One test, because the pool can only be initialized once per instance.
+#[wasm_bindgen_test]
+async fn test_parallel_reduction() {
+    let memory: js_sys::WebAssembly::Memory = wasm_bindgen::memory().unchecked_into();
+    assert!(memory
+        .buffer()
+        .is_instance_of::<js_sys::SharedArrayBuffer>());
+
+    JsFuture::from(threads::init_thread_pool(4)).await.unwrap();
+    assert_eq!(threads::thread_count(), 4);
+
+    let values: Vec<i32> = (1..=100_000).collect();
+    assert_eq!(threads::parallel_sum(&values), 5_000_050_000.0);
+    assert_eq!(threads::parallel_count_primes(10_000), 1_229);
+}
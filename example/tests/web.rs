@@ -7,14 +7,65 @@ use wasm_bindgen_test::*;
 
 wasm_bindgen_test_configure!(run_in_browser);
 
+mod common;
+
 #[wasm_bindgen_test]
-async fn test_greet() {
-    let result = wasm_example::greet("World");
-    assert_eq!(result.as_string().unwrap(), "Hello, World!");
+async fn test_scope_name() {
+    assert_eq!(wasm_example::scope_name(), "Window");
 }
 
-#[wasm_bindgen_test]
-async fn test_add() {
-    let result = wasm_example::add(2, 3);
-    assert_eq!(result.as_f64().unwrap(), 5.0);
+#[cfg(feature = "web")]
+mod dom {
+    use wasm_bindgen_test::*;
+    use wasm_example::dom;
+    use web_sys::Element;
+
+    fn container() -> Element {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let container = document.create_element("div").unwrap();
+        document.body().unwrap().append_child(&container).unwrap();
+        container
+    }
+
+    #[wasm_bindgen_test]
+    fn test_document_title() {
+        dom::set_document_title("wasm_example").unwrap();
+        assert_eq!(dom::document_title().unwrap(), "wasm_example");
+        assert_eq!(
+            web_sys::window().unwrap().document().unwrap().title(),
+            "wasm_example"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_render_list() {
+        let container = container();
+        let list = dom::render_list(&container, vec!["one".into(), "two".into()]).unwrap();
+
+        assert_eq!(list.tag_name(), "UL");
+        assert_eq!(container.first_element_child(), Some(list.clone()));
+        assert_eq!(list.child_element_count(), 2);
+        assert_eq!(list.text_content().unwrap(), "onetwo");
+        container.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn test_click_counter() {
+        let container = container();
+        let counter = dom::ClickCounter::new(&container).unwrap();
+        let button = counter.element();
+        assert_eq!(button.text_content().unwrap(), "Clicked 0 times");
+
+        button.click();
+        button.click();
+        assert_eq!(counter.clicks(), 2);
+        assert_eq!(button.text_content().unwrap(), "Clicked 2 times");
+
+        drop(counter);
+        assert_eq!(container.child_element_count(), 0);
+        // The listener is gone with the closure, so the label stays put.
+        button.click();
+        assert_eq!(button.text_content().unwrap(), "Clicked 2 times");
+        container.remove();
+    }
 }
@@ -0,0 +1,17 @@
+// Allocation workload for `cargo xtask allocators`.
+//
+//   node xtask/js/allocators.cjs <pkg-dir> <rounds>
+//
+// loads a `--target nodejs` package, runs `churn(rounds)` and prints the
+// allocator, the checksum and the wasm memory size before and after, in bytes,
+// on one line separated by spaces.
+"use strict";
+
+const path = require("node:path");
+
+const wasm = require(path.join(path.resolve(process.argv[2]), "wasm_example.js"));
+const rounds = Number(process.argv[3]);
+
+const initial = wasm.memory_bytes();
+const checksum = wasm.churn(rounds);
+console.log(wasm.allocator(), checksum, initial, wasm.memory_bytes());
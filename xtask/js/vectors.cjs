@@ -0,0 +1,193 @@
+// Test vectors for the wasm_example export surface, shared by the xtask
+// harnesses that compare one build against another.
+//
+//   node --experimental-wasm-modules xtask/js/vectors.cjs <pkg-dir> [--no-bigint] [--no-traps]
+//
+// loads `<pkg-dir>/wasm_example.js` (a `--target nodejs` or `--target bundler`
+// package) and prints every result as one JSON object, so two builds can be
+// compared as text.
+//
+// Covers every export of the default feature set except `elapsed_ms`, whose
+// result is a timing. Exports behind cargo features are not in the packages
+// these harnesses build.
+"use strict";
+
+const fs = require("node:fs");
+const path = require("node:path");
+const { pathToFileURL } = require("node:url");
+
+// Runs `f` and records either its result or the shape of what it threw.
+async function outcome(f) {
+  try {
+    return { ok: encode(await f()) };
+  } catch (e) {
+    if (e instanceof Error) {
+      return { throws: { name: e.name, message: e.message, code: e.code } };
+    }
+    return { throws: encode(e) };
+  }
+}
+
+function encode(value) {
+  if (value === undefined) return "undefined";
+  if (typeof value === "bigint") return `${value}n`;
+  if (typeof value === "number" && !Number.isFinite(value)) return String(value);
+  if (typeof value === "number" && Object.is(value, -0)) return "-0";
+  if (ArrayBuffer.isView(value)) return { [value.constructor.name]: Array.from(value, encode) };
+  if (Array.isArray(value)) return value.map(encode);
+  return value;
+}
+
+// With `bigint: false`, skips the exports that take or return `i64`/`u64`.
+// `wasm2js` lowers those to pairs of `i32`, which the BigInt glue can't call.
+// With `traps: false`, skips the panic, which `wasm2js` throws as a plain
+// `Error("abort")` rather than a `WebAssembly.RuntimeError`.
+async function run(wasm, { bigint = true, traps = true } = {}) {
+  const results = {};
+  const record = async (name, f) => {
+    results[name] = await outcome(f);
+  };
+
+  const names = ["World", "", "naïve 🦀", "a".repeat(1000)];
+  for (const [i, name] of names.entries()) {
+    await record(`greet[${i}]`, () => wasm.greet(name));
+  }
+  for (const [a, b] of [[2, 3], [-7, 7], [1 << 30, 1 << 29], [-2147483648, 1]]) {
+    await record(`add(${a}, ${b})`, () => wasm.add(a, b));
+  }
+  for (const [a, b] of [[2, 3], [2147483647, 1], [-2147483648, -1]]) {
+    await record(`checked_add(${a}, ${b})`, () => wasm.checked_add(a, b));
+    await record(`wrapping_add(${a}, ${b})`, () => wasm.wrapping_add(a, b));
+    await record(`saturating_add(${a}, ${b})`, () => wasm.saturating_add(a, b));
+  }
+  for (const [a, b] of [[7, 2], [-7, 2], [1, 0], [-2147483648, -1]]) {
+    await record(`divide(${a}, ${b})`, () => wasm.divide(a, b));
+  }
+  for (const text of ["  hello world", "", "one"]) {
+    await record(`first_word(${JSON.stringify(text)})`, () => wasm.first_word(text));
+  }
+
+  for (const salutation of [0, 2, 3, -1, undefined, 1.5]) {
+    await record(`greet_with(${encode(salutation)})`, () => wasm.greet_with(salutation, "Ada"));
+  }
+  for (const tone of ["formal", "casual", "Formal", null]) {
+    await record(`salutation_for(${JSON.stringify(tone)})`, () => wasm.salutation_for(tone));
+  }
+  await record("tone_of", () => [0, 1, 2].map((salutation) => wasm.tone_of(salutation)));
+
+  await record("parse_port", () => wasm.parse_port("8080"));
+  await record("parse_port(bad)", () => wasm.parse_port("http"));
+  await record("check_range", () => wasm.check_range(11, 0, 10));
+  for (const name of ["ada_99", "", "a_very_long_username", "ada!"]) {
+    await record(`validate_username(${JSON.stringify(name)})`, () => wasm.validate_username(name));
+  }
+
+  await record("checksum", () => wasm.checksum(new Uint8Array([1, 2, 255, 0, 128])));
+  await record("invert", () => wasm.invert(new Uint8Array([0, 200, 255])));
+  await record("normalize", () => wasm.normalize(new Float64Array([2, 4, 6, -1.5])));
+  await record("apply_gain", () => {
+    const samples = new Float32Array([0.5, -1, 2, 1e-3]);
+    wasm.apply_gain(samples, 2.5);
+    return samples;
+  });
+  if (bigint) {
+    for (const x of [-(2n ** 63n), -1n, 0n, 2n ** 53n + 1n, 2n ** 63n - 1n]) {
+      await record(`echo_i64(${x})`, () => wasm.echo_i64(x));
+    }
+    for (const x of [0n, 2n ** 32n, 2n ** 64n - 1n]) {
+      await record(`echo_u64(${x})`, () => wasm.echo_u64(x));
+    }
+  }
+  for (const x of [-0, NaN, Infinity, 0.1, 5e-324, Number.MAX_VALUE]) {
+    await record(`echo_f64(${encode(x)})`, () => wasm.echo_f64(x));
+    if (bigint) {
+      await record(`f64_bits(${encode(x)})`, () => wasm.f64_bits(x));
+    }
+    await record(`echo_f32(${encode(x)})`, () => [wasm.echo_f32(x), wasm.f32_bits(x)]);
+  }
+  await record("echo_usize", () => [wasm.echo_usize(0), wasm.echo_usize(2 ** 32 - 1)]);
+  await record("add_i128", () => wasm.add_i128("-170141183460469231731687303715884105728", "1"));
+  await record("add_i128(overflow)", () => wasm.add_i128("170141183460469231731687303715884105727", "1"));
+
+  await record("to_base64", () => wasm.to_base64(new Uint8Array([0xff, 0, 1, 2])));
+
+  await record("Greeter", () => {
+    const greeter = new wasm.Greeter("Hi");
+    const out = [greeter.greet("Ada")];
+    greeter.salutation = "Welcome";
+    out.push(greeter.greet("Grace"), greeter.history, greeter.count);
+    greeter.free();
+    return out;
+  });
+  await record("ByteBuffer", () => {
+    const buffer = new wasm.ByteBuffer(4);
+    buffer.fill(7);
+    buffer.with_view((view) => {
+      view[1] = 42;
+    });
+    const out = [buffer.len, buffer.get(1), buffer.get(9), buffer.to_array()];
+    buffer.free();
+    return out;
+  });
+
+  await record("Listeners", () => {
+    const target = new EventTarget();
+    const listeners = new wasm.Listeners();
+    const first = listeners.listen(target, "tick");
+    listeners.listen(target, "tick");
+    target.dispatchEvent(new Event("tick"));
+    const out = [listeners.received, listeners.unlisten(first), listeners.unlisten(first)];
+    target.dispatchEvent(new Event("tick"));
+    out.push(listeners.received, listeners.active);
+    listeners.free();
+    target.dispatchEvent(new Event("tick"));
+    return out;
+  });
+  await record("Listeners.listen(non-target)", () => {
+    const listeners = new wasm.Listeners();
+    try {
+      return listeners.listen({}, "tick");
+    } finally {
+      listeners.free();
+    }
+  });
+
+  await record("call_repeatedly", () => wasm.call_repeatedly((i) => i * i, 100));
+  await record("make_counter", () => {
+    const counter = wasm.make_counter(5);
+    return [counter(), counter(), counter()];
+  });
+  await record("delay", () => wasm.delay(0));
+  await record("scope_name", () => wasm.scope_name());
+  await record("set_panic_hook", () => wasm.set_panic_hook());
+  await record("sum_slowly", () => wasm.sum_slowly(new Int32Array([1, 2, 3, 4]), 0));
+  await record("fail_after", () => wasm.fail_after(0, 7));
+
+  // Memory only grows, so `memory_bytes` depends on everything run before it.
+  await record("allocator", () => [wasm.allocator(), wasm.churn(100), wasm.memory_bytes()]);
+
+  // Must stay last: a panic traps, and the instance is unusable afterwards.
+  if (traps) {
+    await record("panic_with", () => wasm.panic_with("boom"));
+  }
+
+  return results;
+}
+
+module.exports = { run };
+
+// `--target bundler` packages are ES modules; `--target nodejs` ones are not.
+async function load(dir) {
+  const glue = path.join(dir, "wasm_example.js");
+  const manifest = path.join(dir, "package.json");
+  const type = fs.existsSync(manifest) ? JSON.parse(fs.readFileSync(manifest, "utf8")).type : undefined;
+  return type === "module" ? import(pathToFileURL(glue)) : require(glue);
+}
+
+if (require.main === module) {
+  const bigint = !process.argv.includes("--no-bigint");
+  const traps = !process.argv.includes("--no-traps");
+  load(path.resolve(process.argv[2]))
+    .then((wasm) => run(wasm, { bigint, traps }))
+    .then((results) => console.log(JSON.stringify(results, null, 2)));
+}
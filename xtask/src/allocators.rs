@@ -0,0 +1,136 @@
+//! `cargo xtask allocators`: builds `wasm_example` with each global allocator
+//! and reports its binary size and the wasm memory `churn` grows it to.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::{flag, size, Result};
+
+const SCRIPT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/js/allocators.cjs");
+
+/// Allocator name and the cargo feature that selects it.
+const ALLOCATORS: &[(&str, Option<&str>)] = &[
+    ("dlmalloc", None),
+    ("talc", Some("talc")),
+    ("lol_alloc", Some("lol_alloc")),
+];
+
+/// Rounds of `churn`; enough to grow memory past its initial size.
+const ROUNDS: &str = "2000";
+
+/// What `js/allocators.cjs` prints.
+#[derive(Debug, PartialEq)]
+struct Workload {
+    allocator: String,
+    checksum: u32,
+    initial_memory: u64,
+    peak_memory: u64,
+}
+
+pub fn run(args: &[String]) -> Result<()> {
+    let wasm_pack = flag(args, "--wasm-pack", "wasm-pack");
+
+    println!(
+        "{:<12} {:>9} {:>9} {:>12} {:>12}",
+        "allocator", "raw", "gzip", "initial mem", "peak mem"
+    );
+    let mut checksums = Vec::new();
+    for &(name, feature) in ALLOCATORS {
+        let out_dir = Path::new("pkg-allocators").join(name);
+        let mut build = Command::new(&wasm_pack);
+        build
+            .args(["build", "example", "--target", "nodejs", "--release"])
+            .arg("--out-dir")
+            .arg(&out_dir);
+        if let Some(feature) = feature {
+            build.args(["--", "--features", feature]);
+        }
+        let status = build
+            .status()
+            .map_err(|err| format!("running {}: {}", wasm_pack.display(), err))?;
+        if !status.success() {
+            return Err(format!("{}: wasm-pack build failed with {}", name, status).into());
+        }
+
+        let pkg = Path::new("example").join(&out_dir);
+        let wasm = fs::read(pkg.join("wasm_example_bg.wasm"))?;
+        let workload = measure(&pkg)?;
+        if workload.allocator != name {
+            return Err(format!("{}: build reports allocator {}", name, workload.allocator).into());
+        }
+        println!(
+            "{:<12} {:>9} {:>9} {:>12} {:>12}",
+            name,
+            wasm.len(),
+            size::gzip_len(&wasm)?,
+            workload.initial_memory,
+            workload.peak_memory
+        );
+        checksums.push((name, workload.checksum));
+    }
+
+    let (first, expected) = checksums[0];
+    for &(name, checksum) in &checksums[1..] {
+        if checksum != expected {
+            return Err(format!(
+                "churn checksum {} under {} differs from {} under {}",
+                checksum, name, expected, first
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+fn measure(pkg: &Path) -> Result<Workload> {
+    let output = Command::new("node")
+        .arg(SCRIPT)
+        .arg(pkg)
+        .arg(ROUNDS)
+        .output()
+        .map_err(|err| format!("running node: {}", err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "allocation workload failed against {}:\n{}",
+            pkg.display(),
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+    parse(&String::from_utf8(output.stdout)?)
+}
+
+/// Parses `<allocator> <checksum> <initial memory> <peak memory>`.
+fn parse(line: &str) -> Result<Workload> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    match fields[..] {
+        [allocator, checksum, initial_memory, peak_memory] => Ok(Workload {
+            allocator: allocator.to_string(),
+            checksum: checksum.parse()?,
+            initial_memory: initial_memory.parse()?,
+            peak_memory: peak_memory.parse()?,
+        }),
+        _ => Err(format!("unexpected workload output: {:?}", line).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_the_workload_line() {
+        assert_eq!(
+            parse("talc 429514037 1179648 1245184\n").unwrap(),
+            Workload {
+                allocator: "talc".to_string(),
+                checksum: 429514037,
+                initial_memory: 1179648,
+                peak_memory: 1245184,
+            }
+        );
+        assert!(parse("talc 429514037\n").is_err());
+        assert!(parse("talc x 1179648 1245184\n").is_err());
+    }
+}
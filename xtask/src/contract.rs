@@ -0,0 +1,252 @@
+//! `cargo xtask contract`: compares the imports and exports of the release
+//! wasm against `example/wasm-contract.txt`.
+//!
+//! Each import and export is one line with its kind and, for functions, its
+//! signature. The hash suffixes wasm-bindgen and rustc append to generated
+//! names are replaced with `*`, so only a change in the set of items or in a
+//! signature shows up as a difference.
+
+use std::fs;
+
+use wasmparser::{ExternalKind, FuncType, Parser, Payload, TypeRef};
+
+use crate::{flag, Result, DEFAULT_WASM};
+
+const DEFAULT_CONTRACT: &str = "example/wasm-contract.txt";
+
+const HEADER: &str = "\
+# Module ABI of example/pkg/wasm_example_bg.wasm, checked by `cargo xtask contract`.
+# Regenerate with `cargo xtask contract --bless` when a change is intended.
+";
+
+pub fn run(args: &[String]) -> Result<()> {
+    let wasm_path = flag(args, "--wasm", DEFAULT_WASM);
+    let contract_path = flag(args, "--contract", DEFAULT_CONTRACT);
+
+    let wasm =
+        fs::read(&wasm_path).map_err(|err| format!("reading {}: {}", wasm_path.display(), err))?;
+    let actual = describe(&wasm)?;
+
+    if args.iter().any(|arg| arg == "--bless") {
+        fs::write(&contract_path, format!("{}{}\n", HEADER, actual.join("\n")))?;
+        println!(
+            "wrote {} entries to {}",
+            actual.len(),
+            contract_path.display()
+        );
+        return Ok(());
+    }
+
+    let expected: Vec<String> = fs::read_to_string(&contract_path)?
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect();
+
+    let changes = diff(&expected, &actual);
+    if changes.is_empty() {
+        println!("{}: {} entries match", wasm_path.display(), actual.len());
+        return Ok(());
+    }
+    for change in &changes {
+        println!("{}", change);
+    }
+    Err(format!(
+        "{} differs from {} in {} entries",
+        wasm_path.display(),
+        contract_path.display(),
+        changes.len()
+    )
+    .into())
+}
+
+/// Sorted contract lines for every import and export of `wasm`.
+pub fn describe(wasm: &[u8]) -> Result<Vec<String>> {
+    let mut types: Vec<FuncType> = Vec::new();
+    // Type index of every function, imported ones first.
+    let mut functions: Vec<u32> = Vec::new();
+    let mut lines = Vec::new();
+    let mut exports = Vec::new();
+
+    for payload in Parser::new(0).parse_all(wasm) {
+        match payload? {
+            Payload::TypeSection(reader) => {
+                for ty in reader.into_iter_err_on_gc_types() {
+                    types.push(ty?);
+                }
+            }
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    let import = import?;
+                    let name = format!("{} {}", import.module, normalize(import.name));
+                    let line = match import.ty {
+                        TypeRef::Func(index) => {
+                            functions.push(index);
+                            format!("import func {} {}", name, signature(&types, index)?)
+                        }
+                        TypeRef::Table(_) => format!("import table {}", name),
+                        TypeRef::Memory(_) => format!("import memory {}", name),
+                        TypeRef::Global(global) => {
+                            format!("import global {} {}", name, global.content_type)
+                        }
+                        TypeRef::Tag(_) => format!("import tag {}", name),
+                    };
+                    lines.push(line);
+                }
+            }
+            Payload::FunctionSection(reader) => {
+                for index in reader {
+                    functions.push(index?);
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    exports.push(export?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for export in exports {
+        let name = normalize(export.name);
+        let line = match export.kind {
+            ExternalKind::Func => {
+                let ty = *functions
+                    .get(export.index as usize)
+                    .ok_or_else(|| format!("export {} refers to a missing function", name))?;
+                format!("export func {} {}", name, signature(&types, ty)?)
+            }
+            ExternalKind::Table => format!("export table {}", name),
+            ExternalKind::Memory => format!("export memory {}", name),
+            ExternalKind::Global => format!("export global {}", name),
+            ExternalKind::Tag => format!("export tag {}", name),
+        };
+        lines.push(line);
+    }
+
+    lines.sort();
+    Ok(lines)
+}
+
+fn signature(types: &[FuncType], index: u32) -> Result<String> {
+    let ty = types
+        .get(index as usize)
+        .ok_or_else(|| format!("missing type {}", index))?;
+    let list = |types: &[wasmparser::ValType]| {
+        types
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    Ok(format!(
+        "({}) -> ({})",
+        list(ty.params()),
+        list(ty.results())
+    ))
+}
+
+/// Replaces a trailing wasm-bindgen (`_0123456789abcdef`) or rustc
+/// (`__h0123456789abcdef`) hash with `*`.
+fn normalize(name: &str) -> String {
+    for prefix_len in [18, 17] {
+        if name.len() > prefix_len {
+            let (head, tail) = name.split_at(name.len() - prefix_len);
+            let hash = match prefix_len {
+                18 => tail.strip_prefix("_h"),
+                _ => tail.strip_prefix('_'),
+            };
+            if let Some(hash) = hash {
+                if hash.len() == 16 && hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return format!("{}_*", head);
+                }
+            }
+        }
+    }
+    name.to_string()
+}
+
+/// `-` for lines only in `expected`, `+` for lines only in `actual`. Both
+/// inputs must be sorted.
+fn diff(expected: &[String], actual: &[String]) -> Vec<String> {
+    let (mut i, mut j) = (0, 0);
+    let mut changes = Vec::new();
+    while i < expected.len() || j < actual.len() {
+        match (expected.get(i), actual.get(j)) {
+            (Some(e), Some(a)) if e == a => {
+                i += 1;
+                j += 1;
+            }
+            (Some(e), Some(a)) if e < a => {
+                changes.push(format!("- {}", e));
+                i += 1;
+            }
+            (Some(e), None) => {
+                changes.push(format!("- {}", e));
+                i += 1;
+            }
+            (_, Some(a)) => {
+                changes.push(format!("+ {}", a));
+                j += 1;
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (module
+    //   (import "wbg" "__wbg_log_0123456789abcdef" (func (param i32)))
+    //   (func $add (param i32 i32) (result i32) local.get 0 local.get 1 i32.add)
+    //   (memory 1)
+    //   (export "add" (func $add))
+    //   (export "memory" (memory 0)))
+    const MODULE: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x0b, 0x02, 0x60, 0x01, 0x7f, 0x00, 0x60, 0x02, 0x7f, 0x7f, 0x01, 0x7f, // type
+        0x02, 0x22, 0x01, 0x03, b'w', b'b', b'g', 0x1a, b'_', b'_', b'w', b'b', b'g', b'_', b'l',
+        b'o', b'g', b'_', b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b',
+        b'c', b'd', b'e', b'f', 0x00, 0x00, // import
+        0x03, 0x02, 0x01, 0x01, // function
+        0x05, 0x03, 0x01, 0x00, 0x01, // memory
+        0x07, 0x10, 0x02, 0x03, b'a', b'd', b'd', 0x00, 0x01, 0x06, b'm', b'e', b'm', b'o', b'r',
+        b'y', 0x02, 0x00, // export
+        0x0a, 0x09, 0x01, 0x07, 0x00, 0x20, 0x00, 0x20, 0x01, 0x6a, 0x0b, // code
+    ];
+
+    #[test]
+    fn describes_imports_and_exports() {
+        assert_eq!(
+            describe(MODULE).unwrap(),
+            vec![
+                "export func add (i32 i32) -> (i32)",
+                "export memory memory",
+                "import func wbg __wbg_log_* (i32) -> ()",
+            ]
+        );
+    }
+
+    #[test]
+    fn normalizes_hashes() {
+        assert_eq!(normalize("__wbg_new_f0796def86e99471"), "__wbg_new_*");
+        assert_eq!(
+            normalize("wasm_bindgen__closure__destroy__h2ddd2829253d665a"),
+            "wasm_bindgen__closure__destroy__*"
+        );
+        assert_eq!(normalize("greet"), "greet");
+    }
+
+    #[test]
+    fn diffs_sorted_lines() {
+        let lines = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            diff(&lines(&["a", "b", "d"]), &lines(&["a", "c", "d", "e"])),
+            vec!["- b", "+ c", "+ e"]
+        );
+    }
+}
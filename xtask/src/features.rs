@@ -0,0 +1,327 @@
+//! `cargo xtask features`: builds each post-MVP feature fixture of
+//! `wasm_example`, checks the module really uses the feature, and checks
+//! `wasm-opt` accepts it with the matching `--enable-*` flag and rejects it
+//! without.
+//!
+//! Current rustc already enables bulk memory, reference types and
+//! multi-value for wasm32, so every build starts from the MVP baseline in
+//! `example/.cargo/mvp.toml` and adds a single feature. That needs nightly
+//! with `rust-src`, for `build-std`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use wasmparser::{BinaryReader, Operator, Parser, Payload, ValType};
+
+use crate::{flag, Result};
+
+struct Fixture {
+    /// Cargo feature of `wasm_example`.
+    cargo_feature: &'static str,
+    /// Passed to rustc as `-C target-feature=+...`.
+    target_feature: &'static str,
+    uses_feature: fn(&Module) -> bool,
+}
+
+/// Relative to `example/`, where wasm-pack runs cargo.
+const MVP_CONFIG: &str = ".cargo/mvp.toml";
+
+const FIXTURES: &[Fixture] = &[
+    Fixture {
+        cargo_feature: "simd",
+        target_feature: "simd128",
+        uses_feature: |module| module.simd_ops > 0,
+    },
+    Fixture {
+        cargo_feature: "bulk-memory",
+        target_feature: "bulk-memory",
+        uses_feature: |module| module.bulk_memory_ops > 0,
+    },
+    Fixture {
+        cargo_feature: "reference-types",
+        target_feature: "reference-types",
+        uses_feature: |module| module.externref_types > 0,
+    },
+    Fixture {
+        cargo_feature: "multivalue",
+        target_feature: "multivalue",
+        uses_feature: |module| module.multi_value_types > 0,
+    },
+];
+
+/// What a module needs from the engine, as far as the fixtures care.
+#[derive(Debug, Default, PartialEq)]
+struct Module {
+    /// Names from the `target_features` custom section written by LLVM.
+    target_features: Vec<String>,
+    simd_ops: usize,
+    bulk_memory_ops: usize,
+    externref_types: usize,
+    multi_value_types: usize,
+}
+
+pub fn run(args: &[String]) -> Result<()> {
+    let wasm_pack = flag(args, "--wasm-pack", "wasm-pack");
+    let wasm_opt = flag(args, "--wasm-opt", "wasm-opt");
+
+    let baseline = inspect(&fs::read(build(&wasm_pack, None)?)?)?;
+    println!("mvp baseline: {}", baseline.target_features.join(" "));
+
+    let mut failures = Vec::new();
+    for fixture in FIXTURES {
+        match check(fixture, &baseline, &wasm_pack, &wasm_opt) {
+            Ok(()) => println!("{}: ok", fixture.cargo_feature),
+            Err(err) => {
+                println!("{}: FAILED", fixture.cargo_feature);
+                failures.push(format!("{}: {}", fixture.cargo_feature, err));
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n").into())
+    }
+}
+
+fn check(fixture: &Fixture, baseline: &Module, wasm_pack: &Path, wasm_opt: &Path) -> Result<()> {
+    // Otherwise the checks below would pass without the fixture.
+    let own = wasm_opt_flag(fixture.target_feature).unwrap_or_default();
+    if (fixture.uses_feature)(baseline)
+        || enable_flags(&baseline.target_features).contains(&own.to_string())
+    {
+        return Err(format!("the MVP baseline already uses {}", fixture.target_feature).into());
+    }
+
+    let wasm_path = build(wasm_pack, Some(fixture))?;
+    let module = inspect(&fs::read(&wasm_path)?)?;
+    if !(fixture.uses_feature)(&module) {
+        return Err(format!(
+            "{} does not use {}",
+            wasm_path.display(),
+            fixture.target_feature
+        )
+        .into());
+    }
+
+    let all = enable_flags(&module.target_features);
+    if !all.iter().any(|flag| flag == own) {
+        return Err(format!("target_features does not list {}", fixture.target_feature).into());
+    }
+    let without: Vec<String> = all.iter().filter(|flag| *flag != own).cloned().collect();
+
+    let input = env::temp_dir().join(format!("features-{}-input.wasm", fixture.cargo_feature));
+    fs::write(&input, strip_target_features(&fs::read(&wasm_path)?)?)?;
+    let output = env::temp_dir().join(format!("features-{}.wasm", fixture.cargo_feature));
+    if !optimize(wasm_opt, &input, &output, &all)? {
+        return Err(format!("wasm-opt rejected the module with {}", all.join(" ")).into());
+    }
+    if optimize(wasm_opt, &input, &output, &without)? {
+        return Err(format!("wasm-opt accepted the module without {}", own).into());
+    }
+    Ok(())
+}
+
+/// Builds the MVP baseline plus `fixture`'s cargo and target feature, if
+/// any, into `example/pkg-features/`, and returns the `.wasm` path.
+fn build(wasm_pack: &Path, fixture: Option<&Fixture>) -> Result<PathBuf> {
+    let name = fixture.map_or("mvp", |fixture| fixture.cargo_feature);
+    let out_dir = Path::new("pkg-features").join(name);
+    let mut command = Command::new(wasm_pack);
+    command
+        .args([
+            "build",
+            "example",
+            "--target",
+            "web",
+            "--release",
+            "--no-opt",
+        ])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .args(["--", "--config", MVP_CONFIG]);
+    if let Some(fixture) = fixture {
+        // Cargo appends these to the `target-cpu=mvp` from the config.
+        command.args(["--features", fixture.cargo_feature]).env(
+            "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUSTFLAGS",
+            format!("-C target-feature=+{}", fixture.target_feature),
+        );
+    }
+    let status = command
+        .status()
+        .map_err(|err| format!("running {}: {}", wasm_pack.display(), err))?;
+    if !status.success() {
+        return Err(format!("wasm-pack build failed with {}", status).into());
+    }
+    Ok(Path::new("example")
+        .join(out_dir)
+        .join("wasm_example_bg.wasm"))
+}
+
+/// Runs `wasm-opt -O` with only `enable` turned on; `Ok(false)` if it fails.
+fn optimize(wasm_opt: &Path, input: &Path, output: &Path, enable: &[String]) -> Result<bool> {
+    let status = Command::new(wasm_opt)
+        .arg("--mvp-features")
+        .args(enable)
+        .arg("-O")
+        .arg(input)
+        .arg("-o")
+        .arg(output)
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|err| format!("running {}: {}", wasm_opt.display(), err))?;
+    Ok(status.success())
+}
+
+/// `wasm-opt` flags for every feature in a `target_features` section.
+fn enable_flags(target_features: &[String]) -> Vec<String> {
+    let mut flags: Vec<String> = target_features
+        .iter()
+        .filter_map(|name| wasm_opt_flag(name))
+        .map(str::to_string)
+        .collect();
+    flags.sort();
+    flags.dedup();
+    flags
+}
+
+/// LLVM feature name to `wasm-opt` flag. Newer LLVM splits features binaryen
+/// still folds into one, so several names can share a flag.
+fn wasm_opt_flag(target_feature: &str) -> Option<&'static str> {
+    Some(match target_feature {
+        "simd128" | "relaxed-simd" => "--enable-simd",
+        "bulk-memory" | "bulk-memory-opt" => "--enable-bulk-memory",
+        "reference-types" | "call-indirect-overlong" => "--enable-reference-types",
+        "multivalue" => "--enable-multivalue",
+        "mutable-globals" => "--enable-mutable-globals",
+        "nontrapping-fptoint" => "--enable-nontrapping-float-to-int",
+        "sign-ext" => "--enable-sign-ext",
+        "atomics" => "--enable-threads",
+        "exception-handling" => "--enable-exception-handling",
+        "extended-const" => "--enable-extended-const",
+        "tail-call" => "--enable-tail-call",
+        _ => return None,
+    })
+}
+
+/// `wasm` without its `target_features` section, which `wasm-opt` would
+/// otherwise read to enable every feature listed, whatever the flags say.
+fn strip_target_features(wasm: &[u8]) -> Result<Vec<u8>> {
+    let mut stripped = wasm[..8].to_vec();
+    let mut reader = BinaryReader::new(&wasm[8..], 8);
+    while !reader.eof() {
+        let start = reader.original_position();
+        let id = reader.read_u8()?;
+        let len = reader.read_var_u32()? as usize;
+        let content = reader.read_bytes(len)?;
+        if id == 0 && BinaryReader::new(content, 0).read_string()? == "target_features" {
+            continue;
+        }
+        stripped.extend_from_slice(&wasm[start..reader.original_position()]);
+    }
+    Ok(stripped)
+}
+
+fn inspect(wasm: &[u8]) -> Result<Module> {
+    let mut module = Module::default();
+    for payload in Parser::new(0).parse_all(wasm) {
+        match payload? {
+            Payload::CustomSection(reader) if reader.name() == "target_features" => {
+                let mut data = BinaryReader::new(reader.data(), 0);
+                for _ in 0..data.read_var_u32()? {
+                    let prefix = data.read_u8()?;
+                    let name = data.read_string()?;
+                    if prefix == b'+' {
+                        module.target_features.push(name.to_string());
+                    }
+                }
+            }
+            Payload::TypeSection(reader) => {
+                for ty in reader.into_iter_err_on_gc_types() {
+                    let ty = ty?;
+                    if ty.results().len() > 1 {
+                        module.multi_value_types += 1;
+                    }
+                    if ty
+                        .params()
+                        .iter()
+                        .chain(ty.results())
+                        .any(|ty| *ty == ValType::EXTERNREF)
+                    {
+                        module.externref_types += 1;
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                let mut ops = body.get_operators_reader()?;
+                while !ops.eof() {
+                    match ops.read()? {
+                        Operator::MemoryFill { .. } | Operator::MemoryCopy { .. } => {
+                            module.bulk_memory_ops += 1
+                        }
+                        Operator::V128Load { .. }
+                        | Operator::V128Store { .. }
+                        | Operator::F32x4Add
+                        | Operator::F32x4Mul
+                        | Operator::F32x4Splat => module.simd_ops += 1,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_target_features_to_flags() {
+        let names: Vec<String> = ["bulk-memory", "bulk-memory-opt", "simd128", "made-up"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            enable_flags(&names),
+            vec!["--enable-bulk-memory", "--enable-simd"]
+        );
+    }
+
+    // (module (type (func (param externref) (result i32 i32))))
+    // plus a `target_features` section listing +multivalue.
+    fn module() -> Vec<u8> {
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        wasm.extend([0x01, 0x07, 0x01, 0x60, 0x01, 0x6f, 0x02, 0x7f, 0x7f]);
+        let mut custom = vec![0x0f];
+        custom.extend(b"target_features");
+        custom.extend([0x01, b'+', 0x0a]);
+        custom.extend(b"multivalue");
+        wasm.push(0x00);
+        wasm.push(custom.len() as u8);
+        wasm.extend(custom);
+        wasm
+    }
+
+    #[test]
+    fn inspects_target_features_and_types() {
+        let module = inspect(&module()).unwrap();
+        assert_eq!(module.target_features, vec!["multivalue"]);
+        assert_eq!(module.multi_value_types, 1);
+        assert_eq!(module.externref_types, 1);
+    }
+
+    #[test]
+    fn strips_only_target_features() {
+        let stripped = strip_target_features(&module()).unwrap();
+        let module = inspect(&stripped).unwrap();
+        assert!(module.target_features.is_empty());
+        assert_eq!(module.multi_value_types, 1);
+        assert_eq!(stripped.len(), 17);
+    }
+}
@@ -0,0 +1,69 @@
+//! Host-side checks for the `wasm_example` build output. Run from the repo
+//! root with `cargo xtask <command>`.
+
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod allocators;
+mod contract;
+mod features;
+mod opt_diff;
+mod size;
+mod vectors;
+mod wasm2js;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Release artifact written by `wasm-pack build ./example --release`.
+pub const DEFAULT_WASM: &str = "example/pkg/wasm_example_bg.wasm";
+
+const USAGE: &str = "usage: cargo xtask <command> [options]
+
+commands:
+  size [--wasm <path>] [--budget <path>] [--no-budget]
+      print per-section sizes and fail if the raw or gzip size is over budget;
+      with --no-budget, only print them
+  allocators [--wasm-pack <path>]
+      build the example with each global allocator and report binary size
+      and peak wasm memory under an allocation-heavy workload
+  contract [--wasm <path>] [--contract <path>] [--bless]
+      diff the module's imports and exports against the checked-in contract
+  features [--wasm-pack <path>] [--wasm-opt <path>]
+      build each post-MVP feature fixture on an MVP baseline (nightly) and
+      check wasm-opt accepts it only with the matching --enable-* flag
+  opt-diff [--pkg <dir>] [--wasm-opt <path>]
+      run the test vectors against the package after wasm-opt at each level
+      and fail on any difference from the unoptimized build
+  wasm2js [--pkg <dir>] [--wasm2js <path>]
+      convert the MVP bundler package to JavaScript with wasm2js and fail on
+      any difference from the wasm build";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("allocators") => allocators::run(&args[1..]),
+        Some("contract") => contract::run(&args[1..]),
+        Some("size") => size::run(&args[1..]),
+        Some("features") => features::run(&args[1..]),
+        Some("opt-diff") => opt_diff::run(&args[1..]),
+        Some("wasm2js") => wasm2js::run(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+/// Looks up `--name <value>` in `args`, falling back to `default`.
+pub fn flag(args: &[String], name: &str, default: &str) -> PathBuf {
+    args.iter()
+        .position(|arg| arg == name)
+        .and_then(|i| args.get(i + 1))
+        .map_or_else(|| Path::new(default).to_path_buf(), PathBuf::from)
+}
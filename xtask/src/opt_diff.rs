@@ -0,0 +1,64 @@
+//! `cargo xtask opt-diff`: runs the release wasm through `wasm-opt` at each
+//! optimization level and checks every variant behaves like the input.
+
+use std::env;
+use std::process::Command;
+
+use crate::{flag, vectors, Result};
+
+/// Unoptimized package from
+/// `wasm-pack build ./example --target nodejs --out-dir pkg-nodejs --release --no-opt`.
+const DEFAULT_PKG: &str = "example/pkg-nodejs";
+
+const WASM: &str = "wasm_example_bg.wasm";
+
+const LEVELS: &[&[&str]] = &[
+    &["-O1"],
+    &["-O3"],
+    &["-Os"],
+    &["-Oz"],
+    &["-O", "--converge"],
+];
+
+pub fn run(args: &[String]) -> Result<()> {
+    let pkg = flag(args, "--pkg", DEFAULT_PKG);
+    let wasm_opt = flag(args, "--wasm-opt", "wasm-opt");
+
+    let baseline = vectors::run(&pkg)?;
+    println!("baseline: {}", pkg.display());
+
+    let mut failures = Vec::new();
+    for level in LEVELS {
+        let label = level.join(" ");
+        let dir = env::temp_dir()
+            .join("wasm-opt-diff")
+            .join(label.replace(' ', ""));
+        vectors::copy_pkg(&pkg, &dir)?;
+
+        let status = Command::new(&wasm_opt)
+            .args(level.iter())
+            .arg(pkg.join(WASM))
+            .arg("-o")
+            .arg(dir.join(WASM))
+            .status()
+            .map_err(|err| format!("running {}: {}", wasm_opt.display(), err))?;
+        if !status.success() {
+            failures.push(format!("wasm-opt {} failed with {}", label, status));
+            continue;
+        }
+
+        match vectors::run(&dir).and_then(|actual| vectors::compare(&label, &baseline, &actual)) {
+            Ok(()) => println!("{}: ok", label),
+            Err(err) => {
+                println!("{}: MISMATCH", label);
+                failures.push(err.to_string());
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n").into())
+    }
+}
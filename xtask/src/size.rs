@@ -0,0 +1,131 @@
+//! `cargo xtask size`: size budget for the release wasm.
+
+use std::fs;
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Deserialize;
+use wasmparser::{Parser, Payload};
+
+use crate::{flag, Result, DEFAULT_WASM};
+
+const DEFAULT_BUDGET: &str = "example/size-budget.toml";
+
+/// Upper bounds in bytes, kept in `example/size-budget.toml`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Budget {
+    pub raw: usize,
+    pub gzip: usize,
+}
+
+pub fn run(args: &[String]) -> Result<()> {
+    let wasm_path = flag(args, "--wasm", DEFAULT_WASM);
+    let budget_path = flag(args, "--budget", DEFAULT_BUDGET);
+    let report_only = args.iter().any(|arg| arg == "--no-budget");
+
+    let wasm =
+        fs::read(&wasm_path).map_err(|err| format!("reading {}: {}", wasm_path.display(), err))?;
+
+    println!("{}", wasm_path.display());
+    for (name, bytes) in section_sizes(&wasm)? {
+        println!("  {:<24} {:>9}", name, bytes);
+    }
+
+    let raw = wasm.len();
+    let gzip = gzip_len(&wasm)?;
+    if report_only {
+        println!("  {:<24} {:>9}", "raw", raw);
+        println!("  {:<24} {:>9}", "gzip", gzip);
+        return Ok(());
+    }
+
+    let budget: Budget = toml::from_str(&fs::read_to_string(&budget_path)?)?;
+    println!("  {:<24} {:>9} / {}", "raw", raw, budget.raw);
+    println!("  {:<24} {:>9} / {}", "gzip", gzip, budget.gzip);
+
+    let mut over = Vec::new();
+    if raw > budget.raw {
+        over.push(format!("raw size {} exceeds budget {}", raw, budget.raw));
+    }
+    if gzip > budget.gzip {
+        over.push(format!("gzip size {} exceeds budget {}", gzip, budget.gzip));
+    }
+    if over.is_empty() {
+        Ok(())
+    } else {
+        Err(over.join("; ").into())
+    }
+}
+
+/// Size of every section in file order, custom sections named by their name.
+pub fn section_sizes(wasm: &[u8]) -> Result<Vec<(String, usize)>> {
+    let mut sizes = Vec::new();
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = payload?;
+        let name = match &payload {
+            Payload::CustomSection(reader) => format!("custom \"{}\"", reader.name()),
+            Payload::CodeSectionStart { .. } => "code".to_string(),
+            _ => match payload.as_section() {
+                Some((id, _)) => section_name(id).to_string(),
+                None => continue,
+            },
+        };
+        if let Some((_, range)) = payload.as_section() {
+            sizes.push((name, range.end - range.start));
+        }
+    }
+    Ok(sizes)
+}
+
+pub fn gzip_len(bytes: &[u8]) -> Result<usize> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(bytes)?;
+    Ok(encoder.finish()?.len())
+}
+
+fn section_name(id: u8) -> &'static str {
+    match id {
+        1 => "type",
+        2 => "import",
+        3 => "function",
+        4 => "table",
+        5 => "memory",
+        6 => "global",
+        7 => "export",
+        8 => "start",
+        9 => "element",
+        10 => "code",
+        11 => "data",
+        12 => "data count",
+        13 => "tag",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (module (memory 1) (export "memory" (memory 0)))
+    const MODULE: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x05, 0x03, 0x01, 0x00, 0x01, // memory
+        0x07, 0x0a, 0x01, 0x06, b'm', b'e', b'm', b'o', b'r', b'y', 0x02, 0x00, // export
+    ];
+
+    #[test]
+    fn sizes_every_section() {
+        let sizes = section_sizes(MODULE).unwrap();
+        assert_eq!(
+            sizes,
+            vec![("memory".to_string(), 3), ("export".to_string(), 10)]
+        );
+    }
+
+    #[test]
+    fn parses_budget() {
+        let budget: Budget = toml::from_str("raw = 10\ngzip = 5\n").unwrap();
+        assert_eq!(budget, Budget { raw: 10, gzip: 5 });
+    }
+}
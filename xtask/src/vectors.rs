@@ -0,0 +1,88 @@
+//! Runs `js/vectors.cjs` against a generated package and compares
+//! the results of two builds.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::Result;
+
+const SCRIPT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/js/vectors.cjs");
+
+/// Results of every test vector against the package in `pkg`, as JSON text.
+pub fn run(pkg: &Path) -> Result<String> {
+    run_with(pkg, &[])
+}
+
+/// [`run`] with extra arguments for `js/vectors.cjs`, e.g. `--no-bigint`.
+pub fn run_with(pkg: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("node")
+        // Lets `--target bundler` glue `import` the `.wasm` file directly.
+        .arg("--experimental-wasm-modules")
+        .arg(SCRIPT)
+        .arg(pkg)
+        .args(args)
+        .output()
+        .map_err(|err| format!("running node: {}", err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "test vectors failed against {}:\n{}",
+            pkg.display(),
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// `Ok` if `actual` matches `expected`, otherwise an error listing the lines
+/// that differ.
+pub fn compare(label: &str, expected: &str, actual: &str) -> Result<()> {
+    if expected == actual {
+        return Ok(());
+    }
+    let mut report = format!("{} does not match the baseline:\n", label);
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    for i in 0..expected.len().max(actual.len()) {
+        let (want, got) = (expected.get(i), actual.get(i));
+        if want != got {
+            report.push_str(&format!(
+                "  line {}:\n    - {}\n    + {}\n",
+                i + 1,
+                want.unwrap_or(&""),
+                got.unwrap_or(&"")
+            ));
+        }
+    }
+    Err(report.into())
+}
+
+/// Copies the files of a generated package, including `snippets/`.
+pub fn copy_pkg(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_pkg(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_reports_changed_lines() {
+        assert!(compare("same", "a\nb\n", "a\nb\n").is_ok());
+
+        let err = compare("-O3", "a\nb\n", "a\nc\n").unwrap_err().to_string();
+        assert!(err.starts_with("-O3 does not match"));
+        assert!(err.contains("line 2:\n    - b\n    + c"));
+    }
+}
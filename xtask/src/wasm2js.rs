@@ -0,0 +1,125 @@
+//! `cargo xtask wasm2js`: converts the release wasm to JavaScript with
+//! `wasm2js` and checks the JS build behaves like the wasm one.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use crate::{flag, vectors, Result};
+
+/// MVP package from
+/// `wasm-pack build --target bundler --out-dir pkg-wasm2js --release --no-opt
+/// -- --config .cargo/mvp.toml`, run in `example/` on nightly. `wasm2js` only
+/// accepts WebAssembly 1.0, and bundler glue imports the module by name, so
+/// it can be pointed at the JS build instead.
+const DEFAULT_PKG: &str = "example/pkg-wasm2js";
+
+/// `wasm2js` legalizes the JS interface, splitting every `i64` parameter and
+/// result into two `i32`s, so the `BigInt` exports only work as wasm. Its
+/// `unreachable` throws `Error("abort")`, so a panic is no `RuntimeError`.
+const VECTOR_ARGS: &[&str] = &["--no-bigint", "--no-traps"];
+
+/// Module for the `env.setTempRet0` import that legalization adds to return
+/// the high half of an `i64`. Bundlers must alias `env` to something like it.
+const ENV_SHIM: &str = "\
+let tempRet0 = 0;
+export function setTempRet0(value) {
+  tempRet0 = value;
+}
+export function getTempRet0() {
+  return tempRet0;
+}
+";
+
+const WASM: &str = "wasm_example_bg.wasm";
+const GLUE: &str = "wasm_example.js";
+
+pub fn run(args: &[String]) -> Result<()> {
+    let pkg = flag(args, "--pkg", DEFAULT_PKG);
+    let wasm2js = flag(args, "--wasm2js", "wasm2js");
+
+    let baseline = vectors::run_with(&pkg, VECTOR_ARGS)?;
+    println!("baseline: {}", pkg.display());
+
+    let dir = env::temp_dir().join("wasm2js");
+    vectors::copy_pkg(&pkg, &dir)?;
+    fs::remove_file(dir.join(WASM))?;
+
+    let js = format!("{}.js", WASM);
+    let status = Command::new(&wasm2js)
+        .arg(pkg.join(WASM))
+        .arg("-O")
+        .arg("-o")
+        .arg(dir.join(&js))
+        .status()
+        .map_err(|err| format!("running {}: {}", wasm2js.display(), err))?;
+    if !status.success() {
+        return Err(format!("wasm2js failed with {}", status).into());
+    }
+
+    let glue = fs::read_to_string(dir.join(GLUE))?;
+    fs::write(dir.join(GLUE), point_at(&glue, &js)?)?;
+    let output = fs::read_to_string(dir.join(&js))?;
+    if let Some(output) = import_env_shim(&output) {
+        fs::write(dir.join("env.js"), ENV_SHIM)?;
+        fs::write(dir.join(&js), output)?;
+    }
+
+    vectors::compare("wasm2js", &baseline, &vectors::run_with(&dir, VECTOR_ARGS)?)?;
+    println!("wasm2js: ok");
+    Ok(())
+}
+
+/// Bundler glue with its `.wasm` import swapped for the `wasm2js` output.
+fn point_at(glue: &str, js: &str) -> Result<String> {
+    let from = format!("\"./{}\"", WASM);
+    if !glue.contains(&from) {
+        return Err(format!(
+            "{} does not import {}; build with --target bundler",
+            GLUE, WASM
+        )
+        .into());
+    }
+    Ok(glue.replace(&from, &format!("\"./{}\"", js)))
+}
+
+/// `wasm2js` output importing `./env.js` instead of the bare `env`, or `None`
+/// if it has no `env` imports.
+fn import_env_shim(output: &str) -> Option<String> {
+    const BARE: &str = "from 'env';";
+    if output.contains(BARE) {
+        Some(output.replace(BARE, "from './env.js';"))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_at_rewrites_the_wasm_import() {
+        let glue = "import * as wasm from \"./wasm_example_bg.wasm\";\n\
+                    import { __wbg_set_wasm } from \"./wasm_example_bg.js\";\n";
+        let rewritten = point_at(glue, "wasm_example_bg.wasm.js").unwrap();
+        assert!(rewritten.contains("from \"./wasm_example_bg.wasm.js\";"));
+        assert!(rewritten.contains("from \"./wasm_example_bg.js\";"));
+    }
+
+    #[test]
+    fn point_at_rejects_other_targets() {
+        let glue = "const bytes = require('fs').readFileSync(path);\n";
+        assert!(point_at(glue, "wasm_example_bg.wasm.js").is_err());
+    }
+
+    #[test]
+    fn import_env_shim_only_touches_env() {
+        let output = "import * as $_wasm_example_bg_js from './wasm_example_bg.js';\n\
+                      import * as env from 'env';\n";
+        let rewritten = import_env_shim(output).unwrap();
+        assert!(rewritten.contains("import * as env from './env.js';"));
+        assert!(rewritten.contains("from './wasm_example_bg.js';"));
+        assert_eq!(import_env_shim("import * as a from './a.js';\n"), None);
+    }
+}